@@ -1,8 +1,23 @@
-use crate::GridNum;
+use anyhow::{Result, anyhow};
+use std::{
+    ops::{Add, Neg, Sub},
+    str::FromStr,
+};
+
+use crate::{Direction, GridNum, inclusive_range};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Coord<T: GridNum>(pub T, pub T);
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MoveResult<T: GridNum> {
+    Moved(Coord<T>),
+    OutOfBounds,
+    Underflow,
+    Overflow,
+}
+
 impl<T: GridNum> Coord<T> {
     pub fn new(x: T, y: T) -> Self {
         Coord(x, y)
@@ -21,53 +36,53 @@ impl<T: GridNum> Coord<T> {
     }
     pub fn up_n(&self, n: Option<T>, min_y: Option<T>) -> Option<Self> {
         let n = n.unwrap_or(T::from(1u8));
+        let new_y = self.1.checked_sub(&n)?;
 
         if let Some(min) = min_y
-            && (self.1 < n || self.1 - n < min)
+            && new_y < min
         {
             return None;
         }
 
-        let new_y = self.1.checked_sub(&n)?;
         Some(Coord(self.0, new_y))
     }
 
     pub fn down_n(&self, n: Option<T>, max_y: Option<T>) -> Option<Self> {
         let n = n.unwrap_or(T::from(1u8));
+        let new_y = self.1.checked_add(&n)?;
 
         if let Some(max) = max_y
-            && self.1 + n > max
+            && new_y > max
         {
             return None;
         }
 
-        let new_y = self.1.checked_add(&n)?;
         Some(Coord(self.0, new_y))
     }
 
     pub fn left_n(&self, n: Option<T>, min_x: Option<T>) -> Option<Self> {
         let n = n.unwrap_or(T::from(1u8));
+        let new_x = self.0.checked_sub(&n)?;
 
         if let Some(min) = min_x
-            && (self.0 < n || self.0 - n < min)
+            && new_x < min
         {
             return None;
         }
 
-        let new_x = self.0.checked_sub(&n)?;
         Some(Coord(new_x, self.1))
     }
 
     pub fn right_n(&self, n: Option<T>, max_x: Option<T>) -> Option<Self> {
         let n = n.unwrap_or(T::from(1u8));
+        let new_x = self.0.checked_add(&n)?;
 
         if let Some(max) = max_x
-            && self.0 + n > max
+            && new_x > max
         {
             return None;
         }
 
-        let new_x = self.0.checked_add(&n)?;
         Some(Coord(new_x, self.1))
     }
     pub fn move_up(&mut self) {
@@ -82,6 +97,49 @@ impl<T: GridNum> Coord<T> {
     pub fn move_right(&mut self) {
         *self = self.right_n(None, None).unwrap()
     }
+    pub fn wrap_up(&self, height: T) -> Self {
+        let y = if self.1 == T::zero() { height } else { self.1 };
+        Coord(self.0, y - T::from(1u8))
+    }
+    pub fn wrap_down(&self, height: T) -> Self {
+        Coord(self.0, (self.1 + T::from(1u8)) % height)
+    }
+    pub fn wrap_left(&self, width: T) -> Self {
+        let x = if self.0 == T::zero() { width } else { self.0 };
+        Coord(x - T::from(1u8), self.1)
+    }
+    pub fn wrap_right(&self, width: T) -> Self {
+        Coord((self.0 + T::from(1u8)) % width, self.1)
+    }
+    pub fn try_move(&self, dir: Direction, bounds: Option<(Coord<T>, Coord<T>)>) -> MoveResult<T> {
+        let one = T::from(1u8);
+        let moved = match dir {
+            Direction::North => self.1.checked_sub(&one).map(|y| Coord(self.0, y)),
+            Direction::South => self.1.checked_add(&one).map(|y| Coord(self.0, y)),
+            Direction::East => self.0.checked_add(&one).map(|x| Coord(x, self.1)),
+            Direction::West => self.0.checked_sub(&one).map(|x| Coord(x, self.1)),
+        };
+
+        let Some(moved) = moved else {
+            return match dir {
+                Direction::North | Direction::West => MoveResult::Underflow,
+                Direction::South | Direction::East => MoveResult::Overflow,
+            };
+        };
+        if let Some((top_left, bottom_right)) = bounds
+            && !moved.range_contains(&top_left, &bottom_right)
+        {
+            return MoveResult::OutOfBounds;
+        }
+
+        MoveResult::Moved(moved)
+    }
+    pub fn checked_step(&self, dir: Direction) -> Option<Self> {
+        match self.try_move(dir, None) {
+            MoveResult::Moved(coord) => Some(coord),
+            MoveResult::OutOfBounds | MoveResult::Underflow | MoveResult::Overflow => None,
+        }
+    }
     pub fn up(&self, range: Option<T>) -> Option<Self> {
         self.up_n(None, range)
     }
@@ -125,27 +183,393 @@ impl<T: GridNum> Coord<T> {
         ]
     }
 
+    fn abs_diff(a: T, b: T) -> T {
+        if a > b { a - b } else { b - a }
+    }
+    pub fn manhattan_distance(&self, other: &Coord<T>) -> T {
+        Self::abs_diff(self.0, other.0) + Self::abs_diff(self.1, other.1)
+    }
+    pub fn chebyshev_distance(&self, other: &Coord<T>) -> T {
+        Self::abs_diff(self.0, other.0).max(Self::abs_diff(self.1, other.1))
+    }
+    pub fn is_orthogonally_adjacent(&self, other: &Coord<T>) -> bool {
+        self.manhattan_distance(other) == T::from(1u8)
+    }
+    pub fn is_adjacent8(&self, other: &Coord<T>) -> bool {
+        self.chebyshev_distance(other) == T::from(1u8)
+    }
+
+    /// Signed `(dx, dy)` from `self` to `other`. Works for any `GridNum`, including
+    /// `i128`/`u128`, but panics if a component difference does not fit in `i64`.
+    /// `cross` and the polygon helpers build on this and share that panic; they then
+    /// compute in `i128`, so they are exact unless the summed cross products exceed
+    /// `i128`, which takes deltas close to the `i64` limit.
+    pub fn delta_to(&self, other: &Coord<T>) -> (i64, i64) {
+        let to_i64 = |(negative, magnitude): (bool, T)| {
+            let magnitude: i64 = magnitude
+                .try_into()
+                .ok()
+                .expect("coordinate delta does not fit in i64");
+            if negative { -magnitude } else { magnitude }
+        };
+
+        (
+            to_i64(Self::signed_diff(self.0, other.0)),
+            to_i64(Self::signed_diff(self.1, other.1)),
+        )
+    }
+
+    pub fn reduced_direction_to(&self, other: &Coord<T>) -> (i64, i64) {
+        let (dx, dy) = self.delta_to(other);
+        let divisor = gcd(dx.abs(), dy.abs());
+        if divisor == 0 {
+            return (0, 0);
+        }
+        (dx / divisor, dy / divisor)
+    }
+
+    /// Integer midpoint, truncating towards zero when a component sum is odd.
+    pub fn midpoint(&self, other: &Coord<T>) -> Self {
+        let two = T::from(2u8);
+        let mid = |a: T, b: T| match a.checked_add(&b) {
+            Some(sum) => sum / two,
+            // the sum overflows, so both values share a sign; step half the distance
+            // from whichever end is nearer zero to keep truncating towards zero
+            None if a < T::zero() => a.max(b) - (a.max(b) - a.min(b)) / two,
+            None => a.min(b) + (a.max(b) - a.min(b)) / two,
+        };
+
+        Coord(mid(self.0, other.0), mid(self.1, other.1))
+    }
+
+    pub fn ray(
+        &self,
+        dir: Direction,
+        bounds: (Coord<T>, Coord<T>),
+    ) -> impl Iterator<Item = Coord<T>> {
+        std::iter::successors(dir.apply(self, Some(bounds)), move |c| {
+            dir.apply(c, Some(bounds))
+        })
+        .take_while(move |c| c.range_contains(&bounds.0, &bounds.1))
+    }
+
+    pub fn step_toward(&self, target: &Coord<T>) -> Self {
+        let one = T::from(1u8);
+        let step = |from: T, to: T| match from.cmp(&to) {
+            std::cmp::Ordering::Less => from + one,
+            std::cmp::Ordering::Greater => from - one,
+            std::cmp::Ordering::Equal => from,
+        };
+
+        Coord(step(self.0, target.0), step(self.1, target.1))
+    }
+
+    pub fn line_to(&self, other: &Coord<T>) -> Vec<Self> {
+        let one = T::from(1u8);
+        let two = T::from(2u8);
+        let step = |from: T, to: T| if to > from { from + one } else { from - one };
+
+        let dx = Self::abs_diff(self.0, other.0);
+        let dy = Self::abs_diff(self.1, other.1);
+
+        let mut current = *self;
+        let mut points = vec![current];
+
+        // error stays within [0, major delta) so it never underflows for unsigned T
+        if dx >= dy {
+            let mut error = dx / two;
+            while current.0 != other.0 {
+                current.0 = step(current.0, other.0);
+                if error < dy {
+                    error = error + (dx - dy);
+                    current.1 = step(current.1, other.1);
+                } else {
+                    error = error - dy;
+                }
+                points.push(current);
+            }
+        } else {
+            let mut error = dy / two;
+            while current.1 != other.1 {
+                current.1 = step(current.1, other.1);
+                if error < dx {
+                    error = error + (dy - dx);
+                    current.0 = step(current.0, other.0);
+                } else {
+                    error = error - dx;
+                }
+                points.push(current);
+            }
+        }
+
+        points
+    }
+
+    pub fn scale(&self, factor: T) -> Option<Self> {
+        self.scale_xy(factor, factor)
+    }
+    pub fn scale_xy(&self, fx: T, fy: T) -> Option<Self> {
+        Some(Coord(self.0.checked_mul(&fx)?, self.1.checked_mul(&fy)?))
+    }
+
+    pub fn try_cast<U: GridNum>(&self) -> Option<Coord<U>>
+    where
+        T: TryInto<U>,
+    {
+        Some(Coord(self.0.try_into().ok()?, self.1.try_into().ok()?))
+    }
+
+    pub fn checked_add(&self, other: &Coord<T>) -> Option<Self> {
+        Some(Coord(
+            self.0.checked_add(&other.0)?,
+            self.1.checked_add(&other.1)?,
+        ))
+    }
+    pub fn checked_sub(&self, other: &Coord<T>) -> Option<Self> {
+        Some(Coord(
+            self.0.checked_sub(&other.0)?,
+            self.1.checked_sub(&other.1)?,
+        ))
+    }
+    pub fn neighbors8(&self, bounds: Option<(Coord<T>, Coord<T>)>) -> Vec<Self> {
+        let (min_x, min_y, max_x, max_y) = match bounds {
+            Some((top_left, bottom_right)) => (
+                Some(top_left.0),
+                Some(top_left.1),
+                Some(bottom_right.0),
+                Some(bottom_right.1),
+            ),
+            None => (None, None, None, None),
+        };
+
+        [
+            self.up_left(min_x, min_y),
+            self.up(min_y),
+            self.up_right(max_x, min_y),
+            self.left(min_x),
+            self.right(max_x),
+            self.down_left(min_x, max_y),
+            self.down(max_y),
+            self.down_right(max_x, max_y),
+        ]
+        .into_iter()
+        .flatten()
+        .filter(|p| match bounds {
+            Some((top_left, bottom_right)) => p.range_contains(&top_left, &bottom_right),
+            None => true,
+        })
+        .collect()
+    }
+
+    pub fn neighbors_in_radius(
+        &self,
+        radius: T,
+        bounds: Option<(Coord<T>, Coord<T>)>,
+    ) -> Vec<Self> {
+        let zero = T::zero();
+        let offsets = |value: T, delta: T| {
+            if delta == zero {
+                vec![Some(value)]
+            } else {
+                vec![value.checked_sub(&delta), value.checked_add(&delta)]
+            }
+        };
+
+        let mut neighbors = Vec::new();
+        for dy in inclusive_range(zero, radius) {
+            let ys: Vec<T> = offsets(self.1, dy).into_iter().flatten().collect();
+            if ys.is_empty() {
+                // both rows are outside the numeric range, as is everything further out
+                break;
+            }
+            for dx in inclusive_range(zero, radius - dy) {
+                let xs: Vec<T> = offsets(self.0, dx).into_iter().flatten().collect();
+                if xs.is_empty() {
+                    break;
+                }
+                for &y in &ys {
+                    for &x in &xs {
+                        neighbors.push(Coord(x, y));
+                    }
+                }
+            }
+        }
+
+        neighbors
+            .into_iter()
+            .filter(|p| p != self)
+            .filter(|p| match bounds {
+                Some((top_left, bottom_right)) => p.range_contains(&top_left, &bottom_right),
+                None => true,
+            })
+            .collect()
+    }
+
+    pub fn bounding_box(coords: &[Coord<T>]) -> Option<(Coord<T>, Coord<T>)> {
+        let (first, rest) = coords.split_first()?;
+        let bounds = rest.iter().fold((*first, *first), |(min, max), c| {
+            (
+                Coord(min.0.min(c.0), min.1.min(c.1)),
+                Coord(max.0.max(c.0), max.1.max(c.1)),
+            )
+        });
+
+        Some(bounds)
+    }
+
+    /// Twice the shoelace area of the closed polygon through `vertices`. Always an
+    /// integer, even when the area itself is a half-integer.
+    pub fn twice_polygon_area(vertices: &[Coord<T>]) -> i128 {
+        let Some((origin, rest)) = vertices.split_first() else {
+            return 0;
+        };
+        let twice_area: i128 = rest
+            .windows(2)
+            .map(|edge| origin.cross(&edge[0], &edge[1]))
+            .sum();
+
+        twice_area.abs()
+    }
+
+    /// Shoelace area of the closed polygon through `vertices`, truncated to an integer.
+    /// Lattice polygons can have half-integer area; use `twice_polygon_area` if that matters.
+    pub fn polygon_area(vertices: &[Coord<T>]) -> i128 {
+        Self::twice_polygon_area(vertices) / 2
+    }
+
+    /// Number of lattice points strictly inside the closed polygon, via Pick's theorem.
+    pub fn interior_point_count(vertices: &[Coord<T>]) -> i128 {
+        if vertices.len() < 3 {
+            return 0;
+        }
+        let boundary: i128 = vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .map(|(a, b)| {
+                let (dx, dy) = a.delta_to(b);
+                i128::from(gcd(dx.abs(), dy.abs()))
+            })
+            .sum();
+
+        // A = i + b/2 - 1, doubled to stay in integers. Collinear vertices have no
+        // interior, which the formula would otherwise report as negative.
+        ((Self::twice_polygon_area(vertices) - boundary + 2) / 2).max(0)
+    }
+
+    /// Total edge length of the closed rectilinear polygon through `vertices`. Every
+    /// edge must be horizontal or vertical; a diagonal edge would be measured as its
+    /// Manhattan length, so debug builds panic on one instead.
+    pub fn polygon_perimeter(vertices: &[Coord<T>]) -> i64 {
+        vertices
+            .iter()
+            .zip(vertices.iter().cycle().skip(1))
+            .map(|(a, b)| {
+                let (dx, dy) = a.delta_to(b);
+                debug_assert!(
+                    dx == 0 || dy == 0,
+                    "edge {a:?} -> {b:?} is not axis-aligned"
+                );
+                dx.abs() + dy.abs()
+            })
+            .sum()
+    }
+
     pub fn points_are_linear(coords: &[Coord<T>]) -> bool {
         // Fewer than 3 points are always collinear
         if coords.len() < 3 {
             return true;
         }
 
-        let (x1, y1) = (coords[0].x(), coords[0].y());
-        let (x2, y2) = (coords[1].x(), coords[1].y());
-        let ref_dx = x2 - x1;
-        let ref_dy = y2 - y1;
+        coords[2..]
+            .iter()
+            .all(|coord| coords[0].cross(&coords[1], coord) == 0)
+    }
+
+    /// Cross product of `self -> a` and `self -> b`. Computed in `i128`, so it is
+    /// exact for any pair of deltas `delta_to` accepts.
+    pub fn cross(&self, a: &Coord<T>, b: &Coord<T>) -> i128 {
+        let (adx, ady) = self.delta_to(a);
+        let (bdx, bdy) = self.delta_to(b);
+        i128::from(adx) * i128::from(bdy) - i128::from(ady) * i128::from(bdx)
+    }
+
+    // (is_negative, magnitude) of `to - from`, so unsigned T never underflows
+    fn signed_diff(from: T, to: T) -> (bool, T) {
+        (to < from, Self::abs_diff(from, to))
+    }
+}
+
+impl<T: GridNum + Neg<Output = T>> Coord<T> {
+    pub fn rotate_cw_about_origin(&self) -> Self {
+        Coord(self.1, -self.0)
+    }
+    pub fn rotate_ccw_about_origin(&self) -> Self {
+        Coord(-self.1, self.0)
+    }
+
+    pub fn spiral_from(center: Coord<T>) -> impl Iterator<Item = Coord<T>> {
+        let one = T::from(1u8);
+        // right, up, left, down; each pair of legs grows by one step
+        let deltas = [
+            Coord(one, T::zero()),
+            Coord(T::zero(), -one),
+            Coord(-one, T::zero()),
+            Coord(T::zero(), one),
+        ];
+        let mut current = center;
+        let mut leg = 0usize;
+        let mut leg_len = 1usize;
+        let mut steps = 0usize;
+        let mut started = false;
 
-        for &coord in &coords[2..] {
-            let dx = coord.x() - x1;
-            let dy = coord.y() - y1;
+        std::iter::from_fn(move || {
+            if !started {
+                started = true;
+                return Some(current);
+            }
 
-            if ref_dx * dy != ref_dy * dx {
-                return false;
+            current = current + deltas[leg % 4];
+            steps += 1;
+            if steps == leg_len {
+                steps = 0;
+                leg += 1;
+                if leg.is_multiple_of(2) {
+                    leg_len += 1;
+                }
             }
-        }
 
-        true
+            Some(current)
+        })
+    }
+}
+
+impl<T: GridNum> Add for Coord<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Coord(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl<T: GridNum> Sub for Coord<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Coord(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl<T: GridNum + Neg<Output = T>> Add<Direction> for Coord<T> {
+    type Output = Self;
+
+    fn add(self, dir: Direction) -> Self {
+        let one = T::from(1u8);
+        match dir {
+            Direction::North => Coord(self.0, self.1 - one),
+            Direction::South => Coord(self.0, self.1 + one),
+            Direction::East => Coord(self.0 + one, self.1),
+            Direction::West => Coord(self.0 - one, self.1),
+        }
     }
 }
 
@@ -161,6 +585,37 @@ impl<T: GridNum> From<Coord<T>> for (T, T) {
     }
 }
 
+impl<T: GridNum + FromStr> FromStr for Coord<T> {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (x, y) = s
+            .split_once(',')
+            .ok_or_else(|| anyhow!("Expected 'x,y', got '{s}'"))?;
+        let parse = |part: &str| {
+            part.trim()
+                .parse::<T>()
+                .map_err(|_| anyhow!("Invalid coordinate component '{}' in '{s}'", part.trim()))
+        };
+        Ok(Coord(parse(x)?, parse(y)?))
+    }
+}
+
+impl<T: GridNum + FromStr> Coord<T> {
+    // accepts whitespace separated pairs, skipping any "->" arrows between them
+    pub fn parse_pair_list(input: &str) -> Result<Vec<Self>> {
+        input
+            .split_whitespace()
+            .filter(|token| *token != "->")
+            .map(str::parse)
+            .collect()
+    }
+}
+
+fn gcd(a: i64, b: i64) -> i64 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -199,6 +654,554 @@ mod tests {
         assert_eq!(c.left_n(Some(1), None), None);
     }
 
+    #[test]
+    fn test_add_sub() {
+        let a = Coord::new(5i32, 3i32);
+        let b = Coord::new(2i32, 7i32);
+        assert_eq!(a + b, Coord::new(7, 10));
+        assert_eq!(a - b, Coord::new(3, -4));
+    }
+
+    #[test]
+    fn test_checked_add_sub() {
+        let a = Coord::new(5u8, 3u8);
+        let b = Coord::new(2u8, 7u8);
+        assert_eq!(a.checked_add(&b), Some(Coord::new(7, 10)));
+        assert_eq!(a.checked_sub(&b), None);
+        assert_eq!(b.checked_sub(&Coord::new(2, 7)), Some(Coord::new(0, 0)));
+        assert_eq!(a.checked_add(&Coord::new(251, 0)), None);
+    }
+
+    #[test]
+    fn test_manhattan_distance() {
+        let a = Coord::new(1u32, 5u32);
+        let b = Coord::new(4u32, 2u32);
+        assert_eq!(a.manhattan_distance(&b), 6);
+        assert_eq!(b.manhattan_distance(&a), 6);
+        assert_eq!(a.manhattan_distance(&a), 0);
+
+        let c = Coord::new(-3i64, 2i64);
+        let d = Coord::new(2i64, -4i64);
+        assert_eq!(c.manhattan_distance(&d), 11);
+        assert_eq!(c.manhattan_distance(&c), 0);
+    }
+
+    #[test]
+    fn test_chebyshev_distance() {
+        let a = Coord::new(5u32, 5u32);
+        assert_eq!(a.chebyshev_distance(&a), 0);
+        assert_eq!(a.chebyshev_distance(&Coord::new(4, 4)), 1);
+        assert_eq!(a.chebyshev_distance(&Coord::new(6, 4)), 1);
+        assert_eq!(a.chebyshev_distance(&Coord::new(2, 7)), 3);
+
+        let b = Coord::new(-2i64, 3i64);
+        assert_eq!(b.chebyshev_distance(&Coord::new(1, -5)), 8);
+    }
+
+    #[test]
+    fn test_neighbors8() {
+        let c = Coord::new(1u32, 1u32);
+        let n = c.neighbors8(None);
+        assert_eq!(n.len(), 8);
+        assert!(n.contains(&Coord::new(0, 0)));
+        assert!(n.contains(&Coord::new(2, 2)));
+        assert!(!n.contains(&c));
+
+        let origin = Coord::new(0u32, 0u32);
+        let n = origin.neighbors8(None);
+        assert_eq!(n.len(), 3);
+
+        let bounds = Some((Coord::new(0u32, 0u32), Coord::new(1u32, 1u32)));
+        let n = c.neighbors8(bounds);
+        assert_eq!(n.len(), 3);
+        assert!(n.contains(&Coord::new(0, 0)));
+        assert!(n.contains(&Coord::new(1, 0)));
+        assert!(n.contains(&Coord::new(0, 1)));
+    }
+
+    #[test]
+    fn test_line_to_horizontal_vertical() {
+        let a = Coord::new(3u32, 2u32);
+        assert_eq!(
+            a.line_to(&Coord::new(0, 2)),
+            vec![
+                Coord::new(3, 2),
+                Coord::new(2, 2),
+                Coord::new(1, 2),
+                Coord::new(0, 2)
+            ]
+        );
+        assert_eq!(
+            a.line_to(&Coord::new(3, 4)),
+            vec![Coord::new(3, 2), Coord::new(3, 3), Coord::new(3, 4)]
+        );
+        assert_eq!(a.line_to(&a), vec![a]);
+    }
+
+    #[test]
+    fn test_line_to_diagonal() {
+        let a = Coord::new(0u32, 2u32);
+        assert_eq!(
+            a.line_to(&Coord::new(2, 0)),
+            vec![Coord::new(0, 2), Coord::new(1, 1), Coord::new(2, 0)]
+        );
+        let b = Coord::new(2i32, 2i32);
+        assert_eq!(
+            b.line_to(&Coord::new(0, 0)),
+            vec![Coord::new(2, 2), Coord::new(1, 1), Coord::new(0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_line_to_shallow() {
+        let a = Coord::new(0u32, 0u32);
+        assert_eq!(
+            a.line_to(&Coord::new(4, 1)),
+            vec![
+                Coord::new(0, 0),
+                Coord::new(1, 0),
+                Coord::new(2, 0),
+                Coord::new(3, 1),
+                Coord::new(4, 1)
+            ]
+        );
+        let steep = a.line_to(&Coord::new(1, 4));
+        assert_eq!(steep.len(), 5);
+        assert_eq!(steep[0], a);
+        assert_eq!(steep[4], Coord::new(1, 4));
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let top_left = Coord::new(0u32, 0u32);
+        assert_eq!(top_left.wrap_left(10), Coord::new(9, 0));
+        assert_eq!(top_left.wrap_up(5), Coord::new(0, 4));
+
+        let bottom_right = Coord::new(9u32, 4u32);
+        assert_eq!(bottom_right.wrap_right(10), Coord::new(0, 4));
+        assert_eq!(bottom_right.wrap_down(5), Coord::new(9, 0));
+
+        let middle = Coord::new(5u32, 2u32);
+        assert_eq!(middle.wrap_left(10), Coord::new(4, 2));
+        assert_eq!(middle.wrap_right(10), Coord::new(6, 2));
+        assert_eq!(middle.wrap_up(5), Coord::new(5, 1));
+        assert_eq!(middle.wrap_down(5), Coord::new(5, 3));
+
+        let narrow = Coord::new(0u8, 10u8);
+        assert_eq!(narrow.wrap_left(250), Coord::new(249, 10));
+        assert_eq!(narrow.wrap_up(250), Coord::new(0, 9));
+        assert_eq!(Coord::new(7u8, 0u8).wrap_up(255), Coord::new(7, 254));
+    }
+
+    #[test]
+    fn test_delta_to() {
+        let a = Coord::new(5u32, 7u32);
+        assert_eq!(a.delta_to(&Coord::new(2, 3)), (-3, -4));
+        assert_eq!(a.delta_to(&Coord::new(9, 7)), (4, 0));
+        assert_eq!(a.delta_to(&a), (0, 0));
+
+        let b = Coord::new(-2i64, 1i64);
+        assert_eq!(b.delta_to(&Coord::new(-5, 3)), (-3, 2));
+
+        let far = Coord::new(i64::MAX as u64, 0u64);
+        assert_eq!(Coord::new(0u64, 0u64).delta_to(&far), (i64::MAX, 0));
+        assert_eq!(far.delta_to(&Coord::new(0, 0)), (-i64::MAX, 0));
+    }
+
+    #[test]
+    fn test_step_toward() {
+        let a = Coord::new(0u32, 0u32);
+        assert_eq!(a.step_toward(&Coord::new(3, 0)), Coord::new(1, 0));
+        assert_eq!(a.step_toward(&Coord::new(0, 5)), Coord::new(0, 1));
+        assert_eq!(a.step_toward(&Coord::new(2, 4)), Coord::new(1, 1));
+        assert_eq!(a.step_toward(&a), a);
+
+        let b = Coord::new(4u32, 4u32);
+        assert_eq!(b.step_toward(&Coord::new(0, 4)), Coord::new(3, 4));
+        assert_eq!(b.step_toward(&Coord::new(4, 3)), Coord::new(4, 3));
+        assert_eq!(b.step_toward(&Coord::new(0, 0)), Coord::new(3, 3));
+    }
+
+    #[test]
+    fn test_neighbors_in_radius() {
+        let c = Coord::new(5u32, 5u32);
+        assert!(c.neighbors_in_radius(0, None).is_empty());
+
+        let mut one = c.neighbors_in_radius(1, None);
+        one.sort();
+        assert_eq!(
+            one,
+            vec![
+                Coord::new(4, 5),
+                Coord::new(5, 4),
+                Coord::new(5, 6),
+                Coord::new(6, 5)
+            ]
+        );
+
+        let two = c.neighbors_in_radius(2, None);
+        assert_eq!(two.len(), 12);
+        assert!(two.iter().all(|p| p.manhattan_distance(&c) <= 2));
+        assert!(two.contains(&Coord::new(3, 5)));
+        assert!(two.contains(&Coord::new(6, 6)));
+
+        let c = Coord::new(250u8, 3u8);
+        let everything = c.neighbors_in_radius(u8::MAX, None);
+        let wide = c.try_cast::<u32>().unwrap();
+        assert!(
+            everything
+                .iter()
+                .all(|p| p.try_cast::<u32>().unwrap().manhattan_distance(&wide) <= 255)
+        );
+        assert!(everything.contains(&Coord::new(0, 0)));
+        assert!(everything.contains(&Coord::new(255, 0)));
+        assert!(!everything.contains(&Coord::new(255, 255)));
+    }
+
+    #[test]
+    fn test_neighbors_in_radius_bounded() {
+        let origin = Coord::new(0u32, 0u32);
+        assert_eq!(origin.neighbors_in_radius(2, None).len(), 5);
+
+        let c = Coord::new(1u32, 1u32);
+        let bounds = Some((Coord::new(0u32, 0u32), Coord::new(2u32, 2u32)));
+        assert_eq!(c.neighbors_in_radius(2, bounds).len(), 8);
+    }
+
+    #[test]
+    fn test_rotate_about_origin() {
+        let start = Coord::new(1i32, 0i32);
+        let cw: Vec<_> = std::iter::successors(Some(start), |c| Some(c.rotate_cw_about_origin()))
+            .take(5)
+            .collect();
+        assert_eq!(
+            cw,
+            vec![
+                Coord::new(1, 0),
+                Coord::new(0, -1),
+                Coord::new(-1, 0),
+                Coord::new(0, 1),
+                Coord::new(1, 0)
+            ]
+        );
+
+        assert_eq!(start.rotate_ccw_about_origin(), Coord::new(0, 1));
+        assert_eq!(
+            Coord::new(3i64, -2i64)
+                .rotate_cw_about_origin()
+                .rotate_ccw_about_origin(),
+            Coord::new(3, -2)
+        );
+    }
+
+    #[test]
+    fn test_cross() {
+        let origin = Coord::new(0i32, 0i32);
+        let east = Coord::new(1, 0);
+        assert!(origin.cross(&east, &Coord::new(1, 1)) > 0);
+        assert!(origin.cross(&east, &Coord::new(1, -1)) < 0);
+        assert_eq!(origin.cross(&east, &Coord::new(5, 0)), 0);
+
+        let a = Coord::new(4u32, 4u32);
+        assert_eq!(a.cross(&Coord::new(2, 2), &Coord::new(0, 0)), 0);
+        assert_eq!(a.cross(&Coord::new(2, 4), &Coord::new(2, 2)), 4);
+
+        let far = 1i64 << 40;
+        let origin = Coord::new(0i64, 0i64);
+        assert_eq!(
+            origin.cross(&Coord::new(far, 0), &Coord::new(0, far)),
+            1i128 << 80
+        );
+    }
+
+    #[test]
+    fn test_reduced_direction_to() {
+        let origin = Coord::new(0i32, 0i32);
+        assert_eq!(origin.reduced_direction_to(&Coord::new(2, 4)), (1, 2));
+        assert_eq!(origin.reduced_direction_to(&Coord::new(0, 5)), (0, 1));
+        assert_eq!(origin.reduced_direction_to(&Coord::new(-3, -6)), (-1, -2));
+        assert_eq!(origin.reduced_direction_to(&Coord::new(-4, 0)), (-1, 0));
+        assert_eq!(origin.reduced_direction_to(&origin), (0, 0));
+
+        let a = Coord::new(6u32, 9u32);
+        assert_eq!(a.reduced_direction_to(&Coord::new(0, 0)), (-2, -3));
+    }
+
+    #[test]
+    fn test_try_cast() {
+        let c = Coord::new(3i64, 7i64);
+        assert_eq!(c.try_cast::<u32>(), Some(Coord::new(3u32, 7u32)));
+        assert_eq!(Coord::new(-1i64, 7i64).try_cast::<u32>(), None);
+        assert_eq!(Coord::new(1i64, 300i64).try_cast::<u8>(), None);
+        assert_eq!(
+            Coord::new(2u8, 9u8).try_cast::<i64>(),
+            Some(Coord::new(2i64, 9i64))
+        );
+    }
+
+    #[test]
+    fn test_try_move() {
+        let bounds = Some((Coord::new(0u8, 0u8), Coord::new(3u8, 3u8)));
+        let c = Coord::new(1u8, 0u8);
+        assert_eq!(
+            c.try_move(Direction::East, bounds),
+            MoveResult::Moved(Coord::new(2, 0))
+        );
+        assert_eq!(c.try_move(Direction::North, bounds), MoveResult::Underflow);
+        assert_eq!(c.try_move(Direction::North, None), MoveResult::Underflow);
+
+        let edge = Coord::new(3u8, 3u8);
+        assert_eq!(
+            edge.try_move(Direction::South, bounds),
+            MoveResult::OutOfBounds
+        );
+        assert_eq!(
+            edge.try_move(Direction::South, None),
+            MoveResult::Moved(Coord::new(3, 4))
+        );
+
+        let max = Coord::new(255u8, 0u8);
+        assert_eq!(max.try_move(Direction::East, None), MoveResult::Overflow);
+        assert_eq!(
+            Coord::new(0u8, 255u8).try_move(Direction::South, bounds),
+            MoveResult::Overflow
+        );
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let coords = [
+            Coord::new(3i32, -1i32),
+            Coord::new(-2, 4),
+            Coord::new(0, 0),
+            Coord::new(5, 2),
+        ];
+        assert_eq!(
+            Coord::bounding_box(&coords),
+            Some((Coord::new(-2, -1), Coord::new(5, 4)))
+        );
+
+        let single = [Coord::new(7u32, 9u32)];
+        assert_eq!(
+            Coord::bounding_box(&single),
+            Some((Coord::new(7, 9), Coord::new(7, 9)))
+        );
+        assert_eq!(Coord::<u32>::bounding_box(&[]), None);
+    }
+
+    #[test]
+    fn test_polygon_area_and_perimeter() {
+        let square = [
+            Coord::new(0u32, 0u32),
+            Coord::new(1, 0),
+            Coord::new(1, 1),
+            Coord::new(0, 1),
+        ];
+        assert_eq!(Coord::polygon_area(&square), 1);
+        assert_eq!(Coord::polygon_perimeter(&square), 4);
+
+        let l_shape = [
+            Coord::new(0i32, 0i32),
+            Coord::new(0, 3),
+            Coord::new(2, 3),
+            Coord::new(2, 1),
+            Coord::new(4, 1),
+            Coord::new(4, 0),
+        ];
+        assert_eq!(Coord::polygon_area(&l_shape), 8);
+        assert_eq!(Coord::polygon_perimeter(&l_shape), 14);
+        assert_eq!(Coord::<i32>::polygon_area(&[]), 0);
+
+        let half = [Coord::new(0i32, 0i32), Coord::new(1, 0), Coord::new(0, 1)];
+        assert_eq!(Coord::twice_polygon_area(&half), 1);
+        assert_eq!(Coord::polygon_area(&half), 0);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "not axis-aligned")]
+    fn test_polygon_perimeter_rejects_diagonal_edges() {
+        Coord::polygon_perimeter(&[Coord::new(0i32, 0i32), Coord::new(4, 0), Coord::new(0, 4)]);
+    }
+
+    #[test]
+    fn test_interior_point_count() {
+        let rectangle = [
+            Coord::new(0u32, 0u32),
+            Coord::new(4, 0),
+            Coord::new(4, 3),
+            Coord::new(0, 3),
+        ];
+        assert_eq!(Coord::interior_point_count(&rectangle), 6);
+
+        let triangle = [Coord::new(0i32, 0i32), Coord::new(4, 0), Coord::new(0, 4)];
+        assert_eq!(Coord::interior_point_count(&triangle), 3);
+
+        assert_eq!(Coord::<i32>::interior_point_count(&[]), 0);
+        assert_eq!(Coord::interior_point_count(&[Coord::new(1u32, 1u32)]), 0);
+        assert_eq!(
+            Coord::interior_point_count(&[Coord::new(0i32, 0i32), Coord::new(3, 3)]),
+            0
+        );
+        let collinear = [Coord::new(0i32, 0i32), Coord::new(2, 0), Coord::new(1, 0)];
+        assert_eq!(Coord::interior_point_count(&collinear), 0);
+    }
+
+    #[test]
+    fn test_spiral_from() {
+        let ring: Vec<_> = Coord::spiral_from(Coord::new(0i32, 0i32)).take(9).collect();
+        assert_eq!(
+            ring,
+            vec![
+                Coord::new(0, 0),
+                Coord::new(1, 0),
+                Coord::new(1, -1),
+                Coord::new(0, -1),
+                Coord::new(-1, -1),
+                Coord::new(-1, 0),
+                Coord::new(-1, 1),
+                Coord::new(0, 1),
+                Coord::new(1, 1)
+            ]
+        );
+
+        let next: Vec<_> = Coord::spiral_from(Coord::new(5i64, 5i64))
+            .skip(9)
+            .take(2)
+            .collect();
+        assert_eq!(next, vec![Coord::new(7, 6), Coord::new(7, 5)]);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        let a = Coord::new(2u32, 4u32);
+        assert_eq!(a.midpoint(&Coord::new(6, 10)), Coord::new(4, 7));
+        assert_eq!(a.midpoint(&Coord::new(5, 7)), Coord::new(3, 5));
+        assert_eq!(
+            Coord::new(-3i32, 4i32).midpoint(&Coord::new(0, -1)),
+            Coord::new(-1, 1)
+        );
+        assert_eq!(
+            Coord::new(250u8, 200u8).midpoint(&Coord::new(254, 100)),
+            Coord::new(252, 150)
+        );
+        assert_eq!(
+            Coord::new(-3i32, -4i32).midpoint(&Coord::new(-4, -7)),
+            Coord::new(-3, -5)
+        );
+        // odd negative sums that overflow round the same way as those that don't
+        assert_eq!(
+            Coord::new(i16::MIN + 1, i16::MIN + 3)
+                .midpoint(&Coord::new(i16::MIN + 2, i16::MIN + 6)),
+            Coord::new(i16::MIN + 2, i16::MIN + 5)
+        );
+        assert_eq!(
+            Coord::new(i16::MAX - 1, 0i16).midpoint(&Coord::new(i16::MAX - 2, 0)),
+            Coord::new(i16::MAX - 2, 0)
+        );
+    }
+
+    #[test]
+    fn test_scale() {
+        let c = Coord::new(3u64, 7u64);
+        assert_eq!(c.scale(2), Some(Coord::new(6, 14)));
+        assert_eq!(c.scale(1_000_000), Some(Coord::new(3_000_000, 7_000_000)));
+        assert_eq!(c.scale_xy(2, 1_000_000), Some(Coord::new(6, 7_000_000)));
+        assert_eq!(Coord::new(-4i64, 2i64).scale(3), Some(Coord::new(-12, 6)));
+        assert_eq!(Coord::new(100u8, 1u8).scale(3), None);
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("498,4".parse::<Coord<u32>>().unwrap(), Coord::new(498, 4));
+        assert_eq!(
+            " -3 ,  7 ".parse::<Coord<i32>>().unwrap(),
+            Coord::new(-3, 7)
+        );
+        assert!("498 4".parse::<Coord<u32>>().is_err());
+        assert!("a,4".parse::<Coord<u32>>().is_err());
+    }
+
+    #[test]
+    fn test_parse_pair_list() {
+        assert_eq!(
+            Coord::<i32>::parse_pair_list("1,2 3,4 5,6").unwrap(),
+            vec![Coord::new(1, 2), Coord::new(3, 4), Coord::new(5, 6)]
+        );
+        assert_eq!(
+            Coord::<u32>::parse_pair_list("498,4 -> 498,6 -> 496,6").unwrap(),
+            vec![Coord::new(498, 4), Coord::new(498, 6), Coord::new(496, 6)]
+        );
+        assert!(Coord::<i32>::parse_pair_list("1,2 34").is_err());
+    }
+
+    #[test]
+    fn test_is_adjacent() {
+        let c = Coord::new(3u32, 3u32);
+        assert!(c.is_orthogonally_adjacent(&Coord::new(3, 2)));
+        assert!(c.is_orthogonally_adjacent(&Coord::new(4, 3)));
+        assert!(!c.is_orthogonally_adjacent(&Coord::new(4, 4)));
+        assert!(c.is_adjacent8(&Coord::new(4, 4)));
+        assert!(c.is_adjacent8(&Coord::new(2, 3)));
+        assert!(!c.is_orthogonally_adjacent(&c));
+        assert!(!c.is_adjacent8(&c));
+        assert!(!c.is_orthogonally_adjacent(&Coord::new(5, 3)));
+        assert!(!c.is_adjacent8(&Coord::new(0, 9)));
+    }
+
+    #[test]
+    fn test_ray() {
+        let bounds = (Coord::new(0u32, 0u32), Coord::new(4u32, 2u32));
+        let east: Vec<_> = Coord::new(0, 1).ray(Direction::East, bounds).collect();
+        assert_eq!(
+            east,
+            vec![
+                Coord::new(1, 1),
+                Coord::new(2, 1),
+                Coord::new(3, 1),
+                Coord::new(4, 1)
+            ]
+        );
+
+        let north: Vec<_> = Coord::new(3, 2).ray(Direction::North, bounds).collect();
+        assert_eq!(north, vec![Coord::new(3, 1), Coord::new(3, 0)]);
+        assert_eq!(Coord::new(0, 1).ray(Direction::West, bounds).count(), 0);
+
+        let signed = (
+            Coord::new(i16::MIN, i16::MIN),
+            Coord::new(i16::MAX, i16::MAX),
+        );
+        assert_eq!(
+            Coord::new(0i16, i16::MIN + 2)
+                .ray(Direction::North, signed)
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn test_add_direction() {
+        let c = Coord::new(0i32, 0i32);
+        assert_eq!(c + Direction::North, Coord::new(0, -1));
+        assert_eq!(c + Direction::South, Coord::new(0, 1));
+        assert_eq!(c + Direction::East, Coord::new(1, 0));
+        assert_eq!(c + Direction::West, Coord::new(-1, 0));
+        assert_eq!(
+            c + Direction::East + Direction::East + Direction::North,
+            Coord::new(2, -1)
+        );
+    }
+
+    #[test]
+    fn test_checked_step() {
+        let c = Coord::new(1u32, 0u32);
+        assert_eq!(c.checked_step(Direction::East), Some(Coord::new(2, 0)));
+        assert_eq!(c.checked_step(Direction::South), Some(Coord::new(1, 1)));
+        assert_eq!(c.checked_step(Direction::West), Some(Coord::new(0, 0)));
+        assert_eq!(c.checked_step(Direction::North), None);
+        assert_eq!(Coord::new(0u8, 255u8).checked_step(Direction::South), None);
+    }
+
     #[test]
     fn test_tuple_conversion() {
         let c = Coord::from((7u32, 8u32));
@@ -216,4 +1219,27 @@ mod tests {
 
         assert!(Coord::points_are_linear(&coords));
     }
+
+    #[test]
+    fn test_points_are_linear_unsigned() {
+        let coords = [Coord::new(4u32, 0u32), Coord::new(2, 1), Coord::new(0, 2)];
+        assert!(Coord::points_are_linear(&coords));
+
+        let coords = [Coord::new(4u32, 4u32), Coord::new(2, 2), Coord::new(6, 6)];
+        assert!(Coord::points_are_linear(&coords));
+
+        let coords = [Coord::new(4u32, 4u32), Coord::new(2, 2), Coord::new(6, 2)];
+        assert!(!Coord::points_are_linear(&coords));
+
+        let coords = [Coord::new(2u32, 2u32), Coord::new(0, 4), Coord::new(4, 4)];
+        assert!(!Coord::points_are_linear(&coords));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let c = Coord::new(3i32, -4i32);
+        let json = serde_json::to_string(&c).unwrap();
+        assert_eq!(serde_json::from_str::<Coord<i32>>(&json).unwrap(), c);
+    }
 }
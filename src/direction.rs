@@ -0,0 +1,209 @@
+use crate::{Coord, GridNum};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Direction::North => Direction::West,
+            Direction::West => Direction::South,
+            Direction::South => Direction::East,
+            Direction::East => Direction::North,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn apply<T: GridNum>(
+        self,
+        coord: &Coord<T>,
+        bounds: Option<(Coord<T>, Coord<T>)>,
+    ) -> Option<Coord<T>> {
+        let (top_left, bottom_right) = match bounds {
+            Some((top_left, bottom_right)) => (Some(top_left), Some(bottom_right)),
+            None => (None, None),
+        };
+
+        match self {
+            Direction::North => coord.up(top_left.map(|c| c.y())),
+            Direction::South => coord.down(bottom_right.map(|c| c.y())),
+            Direction::East => coord.right(bottom_right.map(|c| c.x())),
+            Direction::West => coord.left(top_left.map(|c| c.x())),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    // clockwise order, matching the discriminants
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    pub fn turn_45_cw(self) -> Direction8 {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    pub fn turn_45_ccw(self) -> Direction8 {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    /// `(dx, dy)` for one step, with north being negative y.
+    pub fn delta(self) -> (i64, i64) {
+        match self {
+            Direction8::N => (0, -1),
+            Direction8::NE => (1, -1),
+            Direction8::E => (1, 0),
+            Direction8::SE => (1, 1),
+            Direction8::S => (0, 1),
+            Direction8::SW => (-1, 1),
+            Direction8::W => (-1, 0),
+            Direction8::NW => (-1, -1),
+        }
+    }
+
+    pub fn apply<T: GridNum>(
+        self,
+        coord: &Coord<T>,
+        bounds: Option<(Coord<T>, Coord<T>)>,
+    ) -> Option<Coord<T>> {
+        let (vertical, horizontal) = self.components();
+        let coord = match vertical {
+            Some(dir) => dir.apply(coord, bounds)?,
+            None => *coord,
+        };
+        match horizontal {
+            Some(dir) => dir.apply(&coord, bounds),
+            None => Some(coord),
+        }
+    }
+
+    fn components(self) -> (Option<Direction>, Option<Direction>) {
+        match self {
+            Direction8::N => (Some(Direction::North), None),
+            Direction8::NE => (Some(Direction::North), Some(Direction::East)),
+            Direction8::E => (None, Some(Direction::East)),
+            Direction8::SE => (Some(Direction::South), Some(Direction::East)),
+            Direction8::S => (Some(Direction::South), None),
+            Direction8::SW => (Some(Direction::South), Some(Direction::West)),
+            Direction8::W => (None, Some(Direction::West)),
+            Direction8::NW => (Some(Direction::North), Some(Direction::West)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_turns() {
+        let start = Direction::North;
+        let mut d = start;
+        for _ in 0..4 {
+            d = d.turn_left();
+        }
+        assert_eq!(d, start);
+
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.turn_right().turn_left(), Direction::East);
+    }
+
+    #[test]
+    fn test_opposite() {
+        assert_eq!(Direction::North.opposite(), Direction::South);
+        assert_eq!(Direction::East.opposite(), Direction::West);
+        assert_eq!(Direction::West.opposite().opposite(), Direction::West);
+    }
+
+    #[test]
+    fn test_apply() {
+        let c = Coord::new(1u32, 1u32);
+        assert_eq!(Direction::North.apply(&c, None), Some(Coord::new(1, 0)));
+        assert_eq!(Direction::South.apply(&c, None), Some(Coord::new(1, 2)));
+        assert_eq!(Direction::East.apply(&c, None), Some(Coord::new(2, 1)));
+        assert_eq!(Direction::West.apply(&c, None), Some(Coord::new(0, 1)));
+
+        let origin = Coord::new(0u32, 0u32);
+        assert_eq!(Direction::North.apply(&origin, None), None);
+
+        let bounds = Some((Coord::new(0u32, 0u32), Coord::new(1u32, 1u32)));
+        assert_eq!(Direction::East.apply(&c, bounds), None);
+        assert_eq!(Direction::South.apply(&c, bounds), None);
+        assert_eq!(Direction::West.apply(&c, bounds), Some(Coord::new(0, 1)));
+    }
+
+    #[test]
+    fn test_direction8_turns() {
+        let mut d = Direction8::N;
+        let mut seen = Vec::new();
+        for _ in 0..8 {
+            seen.push(d);
+            d = d.turn_45_cw();
+        }
+        assert_eq!(d, Direction8::N);
+        assert_eq!(seen, Direction8::ALL);
+
+        assert_eq!(Direction8::N.turn_45_ccw(), Direction8::NW);
+        assert_eq!(Direction8::SE.turn_45_cw().turn_45_ccw(), Direction8::SE);
+    }
+
+    #[test]
+    fn test_direction8_apply() {
+        let c = Coord::new(1u32, 1u32);
+        for dir in Direction8::ALL {
+            let (dx, dy) = dir.delta();
+            let moved = dir.apply(&c, None).unwrap();
+            assert_eq!(c.delta_to(&moved), (dx, dy));
+        }
+
+        let origin = Coord::new(0u32, 0u32);
+        assert_eq!(Direction8::NE.apply(&origin, None), None);
+        assert_eq!(Direction8::SE.apply(&origin, None), Some(Coord::new(1, 1)));
+
+        let bounds = Some((Coord::new(0u32, 0u32), Coord::new(1u32, 2u32)));
+        assert_eq!(Direction8::SE.apply(&c, bounds), None);
+        assert_eq!(Direction8::SW.apply(&c, bounds), Some(Coord::new(0, 2)));
+    }
+}
@@ -1,6 +1,6 @@
 #![allow(dead_code, unused_imports, unused_variables)]
 use anyhow::{Result, bail};
-use num_traits::{CheckedAdd, CheckedSub, Num};
+use num_traits::{CheckedAdd, CheckedMul, CheckedSub, Num};
 use std::{
     fmt::{Debug, Display},
     hash::Hash,
@@ -9,10 +9,16 @@ use std::{
 
 use hashbrown::HashMap;
 
+mod automaton;
 mod hash_grid;
 mod linear_grid;
+mod search;
+mod static_grid;
+pub use automaton::*;
 pub use hash_grid::*;
 pub use linear_grid::*;
+pub use search::*;
+pub use static_grid::*;
 
 use crate::Coord;
 
@@ -26,8 +32,11 @@ pub trait GridNum:
     + Ord
     + Num
     + TryInto<usize>
+    + TryFrom<usize>
+    + TryInto<i64>
     + CheckedAdd
     + CheckedSub
+    + CheckedMul
     + Mul
     + std::ops::Add<Output = Self>
     + std::ops::Sub<Output = Self>
@@ -44,8 +53,11 @@ impl<T> GridNum for T where
         + Ord
         + Num
         + TryInto<usize>
+        + TryFrom<usize>
+        + TryInto<i64>
         + CheckedAdd
         + CheckedSub
+        + CheckedMul
         + Mul
         + std::ops::Add<Output = Self>
         + std::ops::Sub<Output = Self>
@@ -53,19 +65,617 @@ impl<T> GridNum for T where
 {
 }
 
-// TODO: Implement methods for StaticGrid
-pub struct StaticGrid<T: Copy, const W: usize, const H: usize> {
-    _data: [[T; W]; H],
-}
-
-pub trait Grid<T: GridNum, V: Copy> {
+pub trait Grid<T: GridNum, V: Clone> {
     fn insert(&mut self, key: Coord<T>, value: V) -> Result<()>;
     fn get(&self, key: &Coord<T>) -> Option<&V>;
-    // fn remove(&mut self, key: &Coord<T>) -> Option<V>;
+    fn get_mut(&mut self, key: &Coord<T>) -> Option<&mut V>;
+    fn remove(&mut self, key: &Coord<T>) -> Option<V>;
+    fn swap(&mut self, a: &Coord<T>, b: &Coord<T>) -> Result<()>;
     fn clear(&mut self);
+    fn iter(&self) -> Box<dyn Iterator<Item = (Coord<T>, V)> + '_>;
     fn matches(&self, key: &Coord<T>, value: V) -> Result<bool>
     where
-        V: PartialOrd;
+        V: PartialEq;
+    fn count_matching(&self, value: V) -> usize
+    where
+        V: PartialEq;
+    fn count_neighbors_matching(
+        &self,
+        coord: &Coord<T>,
+        value: V,
+        connectivity: Connectivity,
+    ) -> usize
+    where
+        V: PartialEq,
+    {
+        connectivity
+            .neighbors(coord, None)
+            .iter()
+            .filter(|n| self.get(n) == Some(&value))
+            .count()
+    }
+    /// Like `insert`, but hands back the value that was displaced, if any.
+    fn insert_replace(&mut self, key: Coord<T>, value: V) -> Result<Option<V>> {
+        if let Some(existing) = self.get_mut(&key) {
+            return Ok(Some(std::mem::replace(existing, value)));
+        }
+        self.insert(key, value)?;
+        Ok(None)
+    }
+    /// Writes `value` to every cell in the inclusive rectangle.
+    fn fill_region(&mut self, top_left: Coord<T>, bottom_right: Coord<T>, value: V) -> Result<()> {
+        self.check_bounds(&top_left)?;
+        self.check_bounds(&bottom_right)?;
+        for coord in coords_in_bounds(&(top_left, bottom_right)) {
+            self.insert(coord, value.clone())?;
+        }
+        Ok(())
+    }
+    fn get_or(&self, key: &Coord<T>, default: V) -> V {
+        self.get(key).cloned().unwrap_or(default)
+    }
+    fn neighbors_with_values(
+        &self,
+        coord: &Coord<T>,
+        connectivity: Connectivity,
+    ) -> Vec<(Coord<T>, V)> {
+        connectivity
+            .neighbors(coord, None)
+            .into_iter()
+            .filter_map(|n| Some((n, self.get(&n)?.clone())))
+            .collect()
+    }
+    fn neighbors_matching(
+        &self,
+        coord: &Coord<T>,
+        pred: impl Fn(&V) -> bool,
+        connectivity: Connectivity,
+    ) -> Vec<Coord<T>>
+    where
+        Self: Sized,
+    {
+        connectivity
+            .neighbors(coord, None)
+            .into_iter()
+            .filter(|n| self.get(n).is_some_and(&pred))
+            .collect()
+    }
+    fn max_value(&self) -> Option<(Coord<T>, V)>
+    where
+        V: Ord,
+    {
+        self.iter().max_by(|(_, a), (_, b)| a.cmp(b))
+    }
+    fn min_value(&self) -> Option<(Coord<T>, V)>
+    where
+        V: Ord,
+    {
+        self.iter().min_by(|(_, a), (_, b)| a.cmp(b))
+    }
+    fn find(&self, value: V) -> Option<Coord<T>>
+    where
+        V: PartialEq;
+    fn find_all(&self, value: V) -> Vec<Coord<T>>
+    where
+        V: PartialEq;
     fn up_n(&self, coord: &Coord<T>, step: T) -> Option<V>;
+    fn down_n(&self, coord: &Coord<T>, step: T) -> Option<V>;
+    fn left_n(&self, coord: &Coord<T>, step: T) -> Option<V>;
+    fn right_n(&self, coord: &Coord<T>, step: T) -> Option<V>;
     fn check_bounds(&self, key: &Coord<T>) -> Result<()>;
+    /// `(width, height)`, or `None` for an empty `HashGrid` or when a side does not
+    /// fit in `T`.
+    fn dimensions(&self) -> Option<(T, T)>;
+    /// Number of cells. Dense grids (`LinearGrid`, `StaticGrid`) always report
+    /// `width * height`, since `clear` resets cells to the empty value rather than
+    /// removing them; `HashGrid` counts populated keys.
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Yields `start..=end`, stopping at `end` without stepping past it, so an
+/// `end` of `T::MAX` never overflows. Empty when `start > end`.
+pub(crate) fn inclusive_range<T: GridNum>(start: T, end: T) -> impl Iterator<Item = T> {
+    let one = T::from(1u8);
+    std::iter::successors((start <= end).then_some(start), move |&v| {
+        (v < end).then(|| v + one)
+    })
+}
+
+// row-major list of every coordinate inside the inclusive bounds
+pub(crate) fn coords_in_bounds<T: GridNum>(bounds: &(Coord<T>, Coord<T>)) -> Vec<Coord<T>> {
+    let (top_left, bottom_right) = bounds;
+    inclusive_range(top_left.y(), bottom_right.y())
+        .flat_map(|y| {
+            inclusive_range(top_left.x(), bottom_right.x()).map(move |x| Coord::new(x, y))
+        })
+        .collect()
+}
+
+pub fn perimeter_coords<T: GridNum>(top_left: Coord<T>, bottom_right: Coord<T>) -> Vec<Coord<T>> {
+    let (left, top) = (top_left.x(), top_left.y());
+    let (right, bottom) = (bottom_right.x(), bottom_right.y());
+    let xs: Vec<T> = inclusive_range(left, right).collect();
+    let ys: Vec<T> = inclusive_range(top, bottom).collect();
+    let mut coords = Vec::new();
+
+    if xs.is_empty() || ys.is_empty() {
+        return coords;
+    }
+
+    // clockwise: top row, right column, bottom row, left column
+    coords.extend(xs.iter().map(|&x| Coord::new(x, top)));
+    coords.extend(ys[1..].iter().map(|&y| Coord::new(right, y)));
+    if bottom > top {
+        coords.extend(xs.iter().rev().skip(1).map(|&x| Coord::new(x, bottom)));
+    }
+    if right > left && ys.len() > 2 {
+        coords.extend(
+            ys[1..ys.len() - 1]
+                .iter()
+                .rev()
+                .map(|&y| Coord::new(left, y)),
+        );
+    }
+
+    coords
+}
+
+pub fn count_line_overlaps<T: GridNum>(
+    segments: &[(Coord<T>, Coord<T>)],
+    include_diagonals: bool,
+) -> usize {
+    let mut counts = HashGrid::<T, usize>::new();
+
+    for (start, end) in segments {
+        if !include_diagonals && start.x() != end.x() && start.y() != end.y() {
+            continue;
+        }
+        for point in start.line_to(end) {
+            if let Ok(count) = counts.entry_or_insert_with(point, || 0) {
+                *count += 1;
+            }
+        }
+    }
+
+    counts.values().filter(|count| *count >= 2).count()
+}
+
+/// Steps from `initial` until a state repeats, returning `(cycle_start, cycle_length)`
+/// where `cycle_start` is the step index at which the repeated state first appeared.
+pub fn find_cycle<G, F>(initial: G, mut step: F) -> (usize, usize)
+where
+    G: Hash + Eq + Clone,
+    F: FnMut(&G) -> G,
+{
+    let mut seen = HashMap::new();
+    let mut state = initial;
+
+    for index in 0.. {
+        if let Some(start) = seen.get(&state) {
+            return (*start, index - start);
+        }
+        let next = step(&state);
+        seen.insert(state, index);
+        state = next;
+    }
+
+    unreachable!("step count overflowed usize")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sum_values<G: Grid<i32, i32>>(grid: &G) -> i32 {
+        grid.iter().map(|(_, v)| v).sum()
+    }
+
+    #[test]
+    fn test_iter_through_trait() {
+        let mut linear = LinearGrid::<i32, i32>::new(3, 3, 1);
+        linear.insert(Coord::new(2, 2), 5).unwrap();
+        assert_eq!(sum_values(&linear), 13);
+
+        let mut hash = HashGrid::<i32, i32>::new();
+        hash.insert(Coord::new(-1, 4), 2).unwrap();
+        hash.insert(Coord::new(7, 0), 3).unwrap();
+        assert_eq!(sum_values(&hash), 5);
+    }
+
+    #[test]
+    fn test_count_neighbors_matching() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("#.#\n.##\n#..").unwrap();
+        let center = Coord::new(1, 1);
+        assert_eq!(
+            grid.count_neighbors_matching(&center, '#', Connectivity::Four),
+            1
+        );
+        assert_eq!(
+            grid.count_neighbors_matching(&center, '#', Connectivity::Eight),
+            4
+        );
+        assert_eq!(
+            grid.count_neighbors_matching(&Coord::new(0, 0), '.', Connectivity::Eight),
+            2
+        );
+    }
+
+    #[test]
+    fn test_neighbors_matching() {
+        let maze = LinearGrid::<i32, char>::from_str_chars("#.###\n#...#\n###.#").unwrap();
+        let open = |c: &char| *c == '.';
+        assert_eq!(
+            maze.neighbors_matching(&Coord::new(1, 1), open, Connectivity::Four),
+            vec![Coord::new(1, 0), Coord::new(2, 1)]
+        );
+        assert_eq!(
+            maze.neighbors_matching(&Coord::new(3, 2), open, Connectivity::Four),
+            vec![Coord::new(3, 1)]
+        );
+        assert_eq!(
+            maze.neighbors_matching(&Coord::new(1, 0), open, Connectivity::Eight)
+                .len(),
+            2
+        );
+        assert!(
+            maze.neighbors_matching(&Coord::new(0, 0), open, Connectivity::Four)
+                .contains(&Coord::new(1, 0))
+        );
+
+        let grids: [&dyn Grid<i32, char>; 2] = [&maze, &HashGrid::new()];
+        assert_eq!(grids[0].get(&Coord::new(1, 1)), Some(&'.'));
+        assert!(grids[1].is_empty());
+    }
+
+    #[test]
+    fn test_matches_partial_eq_only() {
+        #[derive(Debug, Clone, PartialEq)]
+        enum Tile {
+            Wall,
+            Floor(f32),
+        }
+
+        let mut hash = HashGrid::<i32, Tile>::new();
+        hash.insert(Coord::new(0, 0), Tile::Wall).unwrap();
+        hash.insert(Coord::new(1, 0), Tile::Floor(0.5)).unwrap();
+        assert!(hash.matches(&Coord::new(0, 0), Tile::Wall).unwrap());
+        assert!(hash.matches(&Coord::new(1, 0), Tile::Floor(0.5)).unwrap());
+        assert!(!hash.matches(&Coord::new(1, 0), Tile::Wall).unwrap());
+
+        let linear = LinearGrid::<i32, Tile>::new(2, 1, Tile::Wall);
+        assert!(linear.matches(&Coord::new(1, 0), Tile::Wall).unwrap());
+    }
+
+    #[test]
+    fn test_get_or() {
+        let mut hash = HashGrid::<i32, u32>::new()
+            .set_max_x(3)
+            .set_strict_bounds(true);
+        hash.insert(Coord::new(1, 1), 5).unwrap();
+        assert_eq!(hash.get_or(&Coord::new(1, 1), 0), 5);
+        assert_eq!(hash.get_or(&Coord::new(2, 1), 0), 0);
+        assert_eq!(hash.get_or(&Coord::new(9, 1), 7), 7);
+
+        let linear = LinearGrid::<i32, u8>::from_digit_grid("12\n34").unwrap();
+        assert_eq!(linear.get_or(&Coord::new(1, 1), 0), 4);
+        assert_eq!(linear.get_or(&Coord::new(2, 0), 0), 0);
+        assert_eq!(linear.get_or(&Coord::new(-1, 0), 9), 9);
+    }
+
+    #[test]
+    fn test_insert_replace() {
+        let mut hash = HashGrid::<i32, u8>::new()
+            .set_max_x(2)
+            .set_strict_bounds(true);
+        let c = Coord::new(1, 1);
+        assert_eq!(hash.insert_replace(c, 3).unwrap(), None);
+        assert_eq!(hash.insert_replace(c, 4).unwrap(), Some(3));
+        assert_eq!(hash.get(&c), Some(&4));
+        assert!(hash.insert_replace(Coord::new(3, 0), 1).is_err());
+
+        let mut linear = LinearGrid::<i32, char>::from_str_chars("..\n.#").unwrap();
+        assert_eq!(linear.insert_replace(c, 'x').unwrap(), Some('#'));
+        assert_eq!(linear.insert_replace(c, '.').unwrap(), Some('x'));
+        assert!(linear.insert_replace(Coord::new(2, 0), '#').is_err());
+    }
+
+    #[test]
+    fn test_len() {
+        let mut hash = HashGrid::<i32, u8>::new();
+        assert!(hash.is_empty());
+        hash.insert(Coord::new(0, 0), 1).unwrap();
+        hash.insert(Coord::new(9, -4), 2).unwrap();
+        hash.insert(Coord::new(0, 0), 3).unwrap();
+        assert_eq!(hash.len(), 2);
+        hash.remove(&Coord::new(0, 0));
+        assert_eq!(hash.len(), 1);
+
+        // dense grids count every cell, empty or not
+        let linear = LinearGrid::<i32, char>::from_str_chars("#..\n...").unwrap();
+        assert_eq!(linear.len(), 6);
+        assert!(!linear.is_empty());
+        assert!(LinearGrid::<i32, char>::new(0, 0, '.').is_empty());
+        let mut cleared = linear.clone();
+        cleared.clear();
+        assert_eq!(cleared.len(), 6);
+        assert_eq!(cleared.dimensions(), Some((3, 2)));
+
+        let fixed = StaticGrid::<i32, u8, 4, 3>::new(0);
+        assert_eq!(fixed.len(), 12);
+    }
+
+    #[test]
+    fn test_fill_region() {
+        let mut linear = LinearGrid::<i32, char>::new(4, 4, '.');
+        linear
+            .fill_region(Coord::new(1, 1), Coord::new(2, 2), '#')
+            .unwrap();
+        assert_eq!(linear.to_string(), "....\n.##.\n.##.\n....");
+        assert!(
+            linear
+                .fill_region(Coord::new(3, 3), Coord::new(4, 4), '#')
+                .is_err()
+        );
+        assert_eq!(linear.count_matching('#'), 4);
+
+        let mut hash = HashGrid::<i32, char>::new();
+        hash.insert(Coord::new(0, 0), 'x').unwrap();
+        hash.fill_region(Coord::new(1, 1), Coord::new(2, 2), '#')
+            .unwrap();
+        assert_eq!(hash.len(), 5);
+        assert_eq!(hash.get(&Coord::new(0, 0)), Some(&'x'));
+        assert_eq!(hash.get(&Coord::new(2, 1)), Some(&'#'));
+        assert_eq!(hash.get(&Coord::new(3, 2)), None);
+
+        let mut fixed = StaticGrid::<i32, u8, 3, 3>::new(0);
+        fixed
+            .fill_region(Coord::new(0, 1), Coord::new(1, 2), 5)
+            .unwrap();
+        assert_eq!(fixed.count_matching(5), 4);
+        assert_eq!(fixed.get(&Coord::new(2, 2)), Some(&0));
+    }
+
+    #[test]
+    fn test_neighbors_with_values() {
+        let grid = LinearGrid::<i32, u8>::from_digit_grid("123\n456\n789").unwrap();
+        let mut four = grid.neighbors_with_values(&Coord::new(1, 1), Connectivity::Four);
+        four.sort();
+        assert_eq!(
+            four,
+            vec![
+                (Coord::new(0, 1), 4),
+                (Coord::new(1, 0), 2),
+                (Coord::new(1, 2), 8),
+                (Coord::new(2, 1), 6)
+            ]
+        );
+
+        let eight = grid.neighbors_with_values(&Coord::new(1, 1), Connectivity::Eight);
+        assert_eq!(eight.len(), 8);
+        assert_eq!(eight.iter().map(|(_, v)| u32::from(*v)).sum::<u32>(), 40);
+
+        let corner = grid.neighbors_with_values(&Coord::new(0, 0), Connectivity::Eight);
+        assert_eq!(corner.len(), 3);
+        assert!(corner.contains(&(Coord::new(1, 1), 5)));
+    }
+
+    #[test]
+    fn test_at_type_limits() {
+        let c = Coord::new;
+        let (tl, br) = (c(254u8, 254u8), c(255u8, 255u8));
+        let sorted = |mut coords: Vec<Coord<u8>>| {
+            coords.sort();
+            coords
+        };
+        let cases: [(&str, Vec<_>, Vec<_>); 6] = [
+            (
+                "coords_in_bounds",
+                coords_in_bounds(&(tl, br)),
+                vec![c(254, 254), c(255, 254), c(254, 255), c(255, 255)],
+            ),
+            (
+                "perimeter_coords",
+                perimeter_coords(tl, br),
+                vec![c(254, 254), c(255, 254), c(255, 255), c(254, 255)],
+            ),
+            (
+                "fill_region",
+                {
+                    let mut grid = HashGrid::<u8, bool>::new();
+                    grid.fill_region(tl, br, true).unwrap();
+                    sorted(grid.find_all(true))
+                },
+                vec![c(254, 254), c(254, 255), c(255, 254), c(255, 255)],
+            ),
+            (
+                "ray",
+                c(253, 255)
+                    .ray(crate::Direction::East, (c(253, 253), br))
+                    .collect(),
+                vec![c(254, 255), c(255, 255)],
+            ),
+            (
+                "neighbors_in_radius",
+                sorted(br.neighbors_in_radius(1, None)),
+                vec![c(254, 255), c(255, 254)],
+            ),
+            (
+                "life_step",
+                {
+                    let mut grid = HashGrid::<u8, bool>::new();
+                    grid.fill_region(c(253, 255), br, true).unwrap();
+                    let rule = |alive, count| matches!((alive, count), (true, 2) | (_, 3));
+                    let next = life_step(&grid, true, false, rule, (c(253, 253), br)).unwrap();
+                    sorted(next.find_all(true))
+                },
+                vec![c(254, 254), c(254, 255)],
+            ),
+        ];
+        for (name, actual, expected) in cases {
+            assert_eq!(actual, expected, "{name}");
+        }
+
+        let mut grid = HashGrid::<u8, bool>::new();
+        grid.insert(c(254, 255), true).unwrap();
+        grid.insert(c(255, 254), true).unwrap();
+        assert_eq!(grid.render('.', |_| '#'), ".#\n#.", "render");
+    }
+
+    #[test]
+    fn test_min_max_value() {
+        let linear = LinearGrid::<i32, u8>::from_digit_grid("2199\n3987\n9856").unwrap();
+        assert_eq!(linear.min_value(), Some((Coord::new(1, 0), 1)));
+        assert_eq!(linear.max_value().map(|(_, v)| v), Some(9));
+
+        let mut hash = HashGrid::<i32, i32>::new();
+        assert_eq!(hash.max_value(), None);
+        hash.insert(Coord::new(0, 0), 4).unwrap();
+        hash.insert(Coord::new(-3, 2), 11).unwrap();
+        hash.insert(Coord::new(5, 5), -2).unwrap();
+        assert_eq!(hash.max_value(), Some((Coord::new(-3, 2), 11)));
+        assert_eq!(hash.min_value(), Some((Coord::new(5, 5), -2)));
+    }
+
+    #[test]
+    fn test_wide_coordinate_types() {
+        let far = i128::from(i64::MAX) * 4;
+        let a = Coord::new(far, -far);
+        let b = Coord::new(-far, far);
+        assert_eq!(a.manhattan_distance(&b), far * 4);
+        assert_eq!(a.chebyshev_distance(&b), far * 2);
+
+        let mut grid = HashGrid::<i128, u8>::new();
+        grid.insert(a, 1).unwrap();
+        grid.insert(b, 2).unwrap();
+        assert_eq!(grid.get(&a), Some(&1));
+        assert_eq!(grid.get(&b), Some(&2));
+        assert_eq!(
+            grid.bounding_box(),
+            Some((Coord::new(-far, -far), Coord::new(far, far)))
+        );
+
+        let near = Coord::new(far, -far + 3);
+        assert_eq!(a.delta_to(&near), (0, 3));
+        assert_eq!(a.neighbors8(None).len(), 8);
+
+        let side = 5_000_000_000i128;
+        let origin = Coord::new(far, far);
+        assert_eq!(
+            origin.cross(&Coord::new(far + side, far), &Coord::new(far, far + side)),
+            side * side
+        );
+        let square = [
+            origin,
+            Coord::new(far + side, far),
+            Coord::new(far + side, far + side),
+            Coord::new(far, far + side),
+        ];
+        assert_eq!(Coord::polygon_area(&square), side * side);
+        assert_eq!(Coord::twice_polygon_area(&square), 2 * side * side);
+        assert_eq!(
+            Coord::interior_point_count(&square),
+            (side - 1) * (side - 1)
+        );
+        assert_eq!(Coord::polygon_perimeter(&square), 4 * side as i64);
+
+        let unsigned = Coord::new(u128::MAX - 1, 0u128);
+        assert_eq!(unsigned.right(None), Some(Coord::new(u128::MAX, 0)));
+        assert_eq!(unsigned.up(None), None);
+
+        let mut linear = LinearGrid::<isize, u8>::new(2, 2, 0);
+        linear.insert(Coord::new(1, 1), 7).unwrap();
+        assert_eq!(linear.get(&Coord::new(1, 1)), Some(&7));
+        assert_eq!(linear.get(&Coord::new(-1, 0)), None);
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        // 0 -> 1 -> 2 -> 3 -> 4 -> 2 -> ...
+        let step = |n: &u32| if *n == 4 { 2 } else { n + 1 };
+        assert_eq!(find_cycle(0, step), (2, 3));
+
+        // 5 -> 2 -> 1 -> 0 -> 0: a fixed point is a cycle of length one
+        assert_eq!(find_cycle(5u32, |n| n / 2), (3, 1));
+
+        let mut grid = LinearGrid::<i32, char>::from_str_chars("#..").unwrap();
+        let shift = |g: &LinearGrid<i32, char>| {
+            let mut next = g.clone();
+            for x in 0..3 {
+                let value = *g.get(&Coord::new((x + 2) % 3, 0)).unwrap();
+                next.insert(Coord::new(x, 0), value).unwrap();
+            }
+            next
+        };
+        assert_eq!(find_cycle(grid.clone(), shift), (0, 3));
+        grid.insert(Coord::new(1, 0), '#').unwrap();
+        grid.insert(Coord::new(2, 0), '#').unwrap();
+        assert_eq!(find_cycle(grid, shift), (0, 1));
+    }
+
+    #[test]
+    fn test_perimeter_coords() {
+        let ring = perimeter_coords(Coord::new(0u32, 0u32), Coord::new(2u32, 2u32));
+        assert_eq!(
+            ring,
+            vec![
+                Coord::new(0, 0),
+                Coord::new(1, 0),
+                Coord::new(2, 0),
+                Coord::new(2, 1),
+                Coord::new(2, 2),
+                Coord::new(1, 2),
+                Coord::new(0, 2),
+                Coord::new(0, 1)
+            ]
+        );
+        assert_eq!(
+            perimeter_coords(Coord::new(0u8, 0u8), Coord::new(255u8, 255u8)).len(),
+            255 * 4
+        );
+    }
+
+    #[test]
+    fn test_perimeter_coords_degenerate() {
+        let single = Coord::new(4u32, 4u32);
+        assert_eq!(perimeter_coords(single, single), vec![single]);
+
+        let row = perimeter_coords(Coord::new(1i32, 3i32), Coord::new(3i32, 3i32));
+        assert_eq!(
+            row,
+            vec![Coord::new(1, 3), Coord::new(2, 3), Coord::new(3, 3)]
+        );
+
+        let column = perimeter_coords(Coord::new(0u32, 0u32), Coord::new(0u32, 2u32));
+        assert_eq!(
+            column,
+            vec![Coord::new(0, 0), Coord::new(0, 1), Coord::new(0, 2)]
+        );
+    }
+
+    #[test]
+    fn test_count_line_overlaps() {
+        let input = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+        let segments: Vec<(Coord<u32>, Coord<u32>)> = input
+            .lines()
+            .map(|line| {
+                let points = Coord::parse_pair_list(line).unwrap();
+                (points[0], points[1])
+            })
+            .collect();
+
+        assert_eq!(count_line_overlaps(&segments, false), 5);
+        assert_eq!(count_line_overlaps(&segments, true), 12);
+    }
 }
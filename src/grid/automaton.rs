@@ -0,0 +1,61 @@
+use anyhow::Result;
+
+use crate::{Coord, Grid, GridNum, coords_in_bounds};
+
+pub fn life_step<T: GridNum, V: Clone + PartialEq, G: Grid<T, V> + Clone>(
+    grid: &G,
+    alive: V,
+    dead: V,
+    rule: impl Fn(bool, u8) -> bool,
+    bounds: (Coord<T>, Coord<T>),
+) -> Result<G> {
+    let mut next = grid.clone();
+
+    for coord in coords_in_bounds(&bounds) {
+        let is_alive = grid.get(&coord) == Some(&alive);
+        let count = coord
+            .neighbors8(Some(bounds))
+            .iter()
+            .filter(|n| grid.get(n) == Some(&alive))
+            .count() as u8;
+
+        let value = if rule(is_alive, count) {
+            alive.clone()
+        } else {
+            dead.clone()
+        };
+        next.insert(coord, value)?;
+    }
+
+    Ok(next)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{HashGrid, LinearGrid};
+
+    fn conway(alive: bool, count: u8) -> bool {
+        matches!((alive, count), (true, 2) | (_, 3))
+    }
+
+    #[test]
+    fn test_life_step_blinker() {
+        let grid =
+            LinearGrid::<i32, char>::from_str_chars(".....\n..#..\n..#..\n..#..\n.....").unwrap();
+        let bounds = (Coord::new(0, 0), Coord::new(4, 4));
+
+        let step = life_step(&grid, '#', '.', conway, bounds).unwrap();
+        assert_eq!(step.to_string(), ".....\n.....\n.###.\n.....\n.....");
+
+        let step = life_step(&step, '#', '.', conway, bounds).unwrap();
+        assert_eq!(step.to_string(), grid.to_string());
+    }
+
+    #[test]
+    fn test_life_step_bounds() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("...\n...").unwrap();
+        let too_big = (Coord::new(0, 0), Coord::new(2, 2));
+        assert!(life_step(&grid, '#', '.', conway, too_big).is_err());
+    }
+}
@@ -1,25 +1,71 @@
 use anyhow::{Result, bail};
-use hashbrown::HashMap;
+use hashbrown::{DefaultHashBuilder, HashMap};
+use std::hash::{BuildHasher, DefaultHasher, Hash, Hasher};
 
-use crate::{Coord, Grid, GridNum};
+use crate::{Coord, Grid, GridNum, inclusive_range};
 
 #[derive(Debug, Clone, Default)]
-pub struct HashGrid<T: GridNum, V: Copy> {
-    data: HashMap<Coord<T>, V>,
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "T: serde::Serialize, V: serde::Serialize",
+        deserialize = "T: serde::Deserialize<'de>, V: serde::Deserialize<'de>, S: BuildHasher + Default"
+    ))
+)]
+pub struct HashGrid<T: GridNum, V: Clone, S = DefaultHashBuilder> {
+    #[cfg_attr(feature = "serde", serde(with = "pairs"))]
+    data: HashMap<Coord<T>, V, S>,
     min_x: Option<T>,
     max_x: Option<T>,
     min_y: Option<T>,
     max_y: Option<T>,
+    strict_bounds: bool,
 }
 
-impl<T: GridNum, V: Copy> HashGrid<T, V> {
+// JSON map keys must be strings, so the cells are stored as a list of pairs
+#[cfg(feature = "serde")]
+mod pairs {
+    use hashbrown::HashMap;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::hash::{BuildHasher, Hash};
+
+    pub fn serialize<S, K, V, H>(map: &HashMap<K, V, H>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        K: Serialize,
+        V: Serialize,
+    {
+        serializer.collect_seq(map.iter())
+    }
+
+    pub fn deserialize<'de, D, K, V, H>(deserializer: D) -> Result<HashMap<K, V, H>, D::Error>
+    where
+        D: Deserializer<'de>,
+        K: Deserialize<'de> + Eq + Hash,
+        V: Deserialize<'de>,
+        H: BuildHasher + Default,
+    {
+        let pairs = Vec::<(K, V)>::deserialize(deserializer)?;
+        Ok(pairs.into_iter().collect())
+    }
+}
+
+impl<T: GridNum, V: Clone> HashGrid<T, V> {
     pub fn new() -> Self {
+        Self::new_with_hasher(DefaultHashBuilder::default())
+    }
+}
+
+impl<T: GridNum, V: Clone, S: BuildHasher> HashGrid<T, V, S> {
+    pub fn new_with_hasher(hasher: S) -> Self {
         Self {
-            data: HashMap::new(),
+            data: HashMap::with_hasher(hasher),
             min_x: None,
             max_x: None,
             min_y: None,
             max_y: None,
+            strict_bounds: false,
         }
     }
 
@@ -43,12 +89,138 @@ impl<T: GridNum, V: Copy> HashGrid<T, V> {
         self
     }
 
+    pub fn set_strict_bounds(mut self, strict_bounds: bool) -> Self {
+        self.strict_bounds = strict_bounds;
+        self
+    }
+
     pub fn insert_or_ignore(&mut self, key: Coord<T>, value: V) -> Result<()> {
         self.check_bounds(&key)?;
         self.data.entry(key).or_insert(value);
         Ok(())
     }
 
+    pub fn insert_many(&mut self, items: impl IntoIterator<Item = (Coord<T>, V)>) -> Result<()> {
+        let items = items
+            .into_iter()
+            .map(|(key, value)| self.check_bounds(&key).map(|_| (key, value)))
+            .collect::<Result<Vec<_>>>()?;
+        self.data.extend(items);
+        Ok(())
+    }
+
+    /// Copies every cell of `other` into `self`, combining collisions with
+    /// `resolve(existing, incoming)`. Nothing is merged if any key is out of bounds.
+    pub fn merge(&mut self, other: &HashGrid<T, V, S>, resolve: impl Fn(V, V) -> V) -> Result<()> {
+        for key in other.data.keys() {
+            self.check_bounds(key)?;
+        }
+        for (key, incoming) in &other.data {
+            let value = match self.data.remove(key) {
+                Some(existing) => resolve(existing, incoming.clone()),
+                None => incoming.clone(),
+            };
+            self.data.insert(*key, value);
+        }
+        Ok(())
+    }
+
+    /// Iterates over the populated cells. Ordering is unspecified.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord<T>, V)> + '_ {
+        self.data.iter().map(|(k, v)| (*k, v.clone()))
+    }
+
+    /// Iterates over the populated coordinates. Ordering is unspecified.
+    pub fn keys(&self) -> impl Iterator<Item = Coord<T>> + '_ {
+        self.data.keys().cloned()
+    }
+
+    /// Iterates over the populated values. Ordering is unspecified.
+    pub fn values(&self) -> impl Iterator<Item = V> + '_ {
+        self.data.values().cloned()
+    }
+
+    pub fn entry_or_insert_with(&mut self, key: Coord<T>, f: impl FnOnce() -> V) -> Result<&mut V> {
+        self.check_bounds(&key)?;
+        Ok(self.data.entry(key).or_insert_with(f))
+    }
+
+    /// Iterates mutably over the populated values. Ordering is unspecified.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> + '_ {
+        self.data.values_mut()
+    }
+
+    pub fn bounding_box(&self) -> Option<(Coord<T>, Coord<T>)> {
+        let mut keys = self.data.keys();
+        let first = *keys.next()?;
+        let (top_left, bottom_right) = keys.fold((first, first), |(tl, br), k| {
+            (
+                Coord(tl.x().min(k.x()), tl.y().min(k.y())),
+                Coord(br.x().max(k.x()), br.y().max(k.y())),
+            )
+        });
+
+        Some((top_left, bottom_right))
+    }
+
+    pub fn render(&self, empty: char, fmt: impl Fn(&V) -> char) -> String {
+        let Some((top_left, bottom_right)) = self.bounding_box() else {
+            return String::new();
+        };
+        inclusive_range(top_left.y(), bottom_right.y())
+            .map(|y| {
+                inclusive_range(top_left.x(), bottom_right.x())
+                    .map(|x| self.data.get(&Coord(x, y)).map_or(empty, &fmt))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Folds over the populated cells in row-major order (by `y`, then `x`), so the
+    /// result is reproducible regardless of hash ordering.
+    pub fn fold_sorted<A>(&self, init: A, f: impl Fn(A, Coord<T>, &V) -> A) -> A {
+        let mut keys: Vec<_> = self.data.keys().collect();
+        keys.sort_by_key(|c| (c.y(), c.x()));
+        keys.into_iter()
+            .fold(init, |acc, key| f(acc, *key, &self.data[key]))
+    }
+
+    pub fn translate(&mut self, dx: T, dy: T) -> Result<()> {
+        let offset = Coord(dx, dy);
+        // Some(None) keeps an unset bound unset; None means the shift overflowed
+        let shift = |bound: Option<T>, delta: T| match bound {
+            Some(bound) => bound.checked_add(&delta).map(Some),
+            None => Some(None),
+        };
+        let (Some(min_x), Some(max_x), Some(min_y), Some(max_y)) = (
+            shift(self.min_x, dx),
+            shift(self.max_x, dx),
+            shift(self.min_y, dy),
+            shift(self.max_y, dy),
+        ) else {
+            bail!("Translated bounds overflow the coordinate type");
+        };
+
+        let shifted = self
+            .data
+            .iter()
+            .map(|(k, v)| match k.checked_add(&offset) {
+                Some(key) => Ok((key, v.clone())),
+                None => bail!("Translated key overflows the coordinate type"),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.data.clear();
+        self.data.extend(shifted);
+        self.min_x = min_x;
+        self.max_x = max_x;
+        self.min_y = min_y;
+        self.max_y = max_y;
+
+        Ok(())
+    }
+
     pub fn contains_key(&self, key: &Coord<T>) -> bool {
         if self.check_bounds(key).is_err() {
             return false;
@@ -57,15 +229,53 @@ impl<T: GridNum, V: Copy> HashGrid<T, V> {
     }
 }
 
-impl<T: GridNum, V: Copy> Grid<T, V> for HashGrid<T, V> {
+impl<T: GridNum, V: Clone, S: BuildHasher + Default> FromIterator<(Coord<T>, V)>
+    for HashGrid<T, V, S>
+{
+    fn from_iter<I: IntoIterator<Item = (Coord<T>, V)>>(iter: I) -> Self {
+        let mut grid = Self::new_with_hasher(S::default());
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T: GridNum, V: Clone, S: BuildHasher> Extend<(Coord<T>, V)> for HashGrid<T, V, S> {
+    fn extend<I: IntoIterator<Item = (Coord<T>, V)>>(&mut self, iter: I) {
+        self.data.extend(iter);
+    }
+}
+
+// Only the populated cells are compared; bounds are configuration, not state.
+impl<T: GridNum, V: Clone + PartialEq, S: BuildHasher> PartialEq for HashGrid<T, V, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+impl<T: GridNum, V: Clone + Eq, S: BuildHasher> Eq for HashGrid<T, V, S> {}
+
+// Map iteration order is unspecified, so each entry is hashed on its own with a
+// fixed-key hasher and the results are combined with an order-independent XOR.
+impl<T: GridNum, V: Clone + Hash, S: BuildHasher> Hash for HashGrid<T, V, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let combined = self.data.iter().fold(0u64, |acc, entry| {
+            let mut hasher = DefaultHasher::new();
+            entry.hash(&mut hasher);
+            acc ^ hasher.finish()
+        });
+        state.write_usize(self.data.len());
+        state.write_u64(combined);
+    }
+}
+
+impl<T: GridNum, V: Clone, S: BuildHasher> Grid<T, V> for HashGrid<T, V, S> {
     fn clear(&mut self) {
         self.data.clear();
     }
 
     fn check_bounds(&self, key: &Coord<T>) -> Result<()> {
-        // only check bounds in debug mode for performance
-        #[cfg(debug_assertions)]
-        {
+        // only check bounds in debug mode for performance, unless strict bounds are requested
+        if cfg!(debug_assertions) || self.strict_bounds {
             if let Some(min_x) = self.min_x
                 && key.x() < min_x
             {
@@ -89,6 +299,23 @@ impl<T: GridNum, V: Copy> Grid<T, V> for HashGrid<T, V> {
         }
         Ok(())
     }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn dimensions(&self) -> Option<(T, T)> {
+        let one = T::from(1u8);
+        let populated = self.bounding_box();
+        let min_x = self.min_x.or(populated.map(|(tl, _)| tl.x()))?;
+        let max_x = self.max_x.or(populated.map(|(_, br)| br.x()))?;
+        let min_y = self.min_y.or(populated.map(|(tl, _)| tl.y()))?;
+        let max_y = self.max_y.or(populated.map(|(_, br)| br.y()))?;
+
+        let span = |min: T, max: T| max.checked_sub(&min)?.checked_add(&one);
+        Some((span(min_x, max_x)?, span(min_y, max_y)?))
+    }
+
     fn insert(&mut self, key: Coord<T>, value: V) -> Result<()> {
         self.check_bounds(&key)?;
         self.data.insert(key, value);
@@ -101,21 +328,91 @@ impl<T: GridNum, V: Copy> Grid<T, V> for HashGrid<T, V> {
         self.data.get(key)
     }
 
-    // fn remove(&mut self, key: &Coord<T>) -> Option<V> {
-    //     self.check_bounds(key).ok()?;
-    //     self.data.remove(key)
-    // }
+    fn get_mut(&mut self, key: &Coord<T>) -> Option<&mut V> {
+        self.check_bounds(key).ok()?;
+        self.data.get_mut(key)
+    }
+
+    fn remove(&mut self, key: &Coord<T>) -> Option<V> {
+        self.check_bounds(key).ok()?;
+        self.data.remove(key)
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Coord<T>, V)> + '_> {
+        Box::new(HashGrid::iter(self))
+    }
+
+    fn swap(&mut self, a: &Coord<T>, b: &Coord<T>) -> Result<()> {
+        self.check_bounds(a)?;
+        self.check_bounds(b)?;
+        let value_a = self.data.remove(a);
+        let value_b = self.data.remove(b);
+        if let Some(value) = value_a {
+            self.data.insert(*b, value);
+        }
+        if let Some(value) = value_b {
+            self.data.insert(*a, value);
+        }
+        Ok(())
+    }
+
+    fn count_matching(&self, value: V) -> usize
+    where
+        V: PartialEq,
+    {
+        self.data.values().filter(|v| **v == value).count()
+    }
+
+    fn find(&self, value: V) -> Option<Coord<T>>
+    where
+        V: PartialEq,
+    {
+        self.data
+            .iter()
+            .find_map(|(k, v)| (*v == value).then_some(*k))
+    }
+
+    fn find_all(&self, value: V) -> Vec<Coord<T>>
+    where
+        V: PartialEq,
+    {
+        self.data
+            .iter()
+            .filter_map(|(k, v)| (*v == value).then_some(*k))
+            .collect()
+    }
 
     fn up_n(&self, coord: &Coord<T>, step: T) -> Option<V> {
-        let new_coord = Coord::new(coord.x(), coord.y() - step);
+        let new_coord = coord.up_n(Some(step), None)?;
+        self.check_bounds(&new_coord).ok()?;
+
+        self.data.get(&new_coord).cloned()
+    }
+
+    fn down_n(&self, coord: &Coord<T>, step: T) -> Option<V> {
+        let new_coord = coord.down_n(Some(step), None)?;
         self.check_bounds(&new_coord).ok()?;
 
-        self.data.get(&new_coord).copied()
+        self.data.get(&new_coord).cloned()
+    }
+
+    fn left_n(&self, coord: &Coord<T>, step: T) -> Option<V> {
+        let new_coord = coord.left_n(Some(step), None)?;
+        self.check_bounds(&new_coord).ok()?;
+
+        self.data.get(&new_coord).cloned()
+    }
+
+    fn right_n(&self, coord: &Coord<T>, step: T) -> Option<V> {
+        let new_coord = coord.right_n(Some(step), None)?;
+        self.check_bounds(&new_coord).ok()?;
+
+        self.data.get(&new_coord).cloned()
     }
 
     fn matches(&self, key: &Coord<T>, value: V) -> Result<bool>
     where
-        V: PartialOrd,
+        V: PartialEq,
     {
         self.check_bounds(key)?;
         match self.data.get(key) {
@@ -150,14 +447,49 @@ mod tests {
         assert_eq!(grid.get(&c), Some(&10));
     }
 
-    // #[test]
-    // fn test_remove() {
-    //     let mut grid = HashGrid::<i32, i32>::new();
-    //     let c = coord(5, 6);
-    //     grid.insert(c, 7).unwrap();
-    //     assert_eq!(grid.remove(&c), Some(7));
-    //     assert_eq!(grid.get(&c), None);
-    // }
+    #[test]
+    fn test_remove() {
+        let mut grid = HashGrid::<i32, i32>::new();
+        let c = coord(5, 6);
+        grid.insert(c, 7).unwrap();
+        assert_eq!(grid.remove(&c), Some(7));
+        assert_eq!(grid.get(&c), None);
+    }
+
+    #[test]
+    fn test_entry_or_insert_with() {
+        let mut grid = HashGrid::<i32, i32>::new();
+        let c = coord(3, 4);
+        *grid.entry_or_insert_with(c, || 10).unwrap() += 1;
+        *grid
+            .entry_or_insert_with(c, || panic!("closure called for existing key"))
+            .unwrap() += 1;
+        assert_eq!(grid.get(&c), Some(&12));
+
+        let mut bounded = HashGrid::<i32, i32>::new()
+            .set_max_x(2)
+            .set_strict_bounds(true);
+        assert!(bounded.entry_or_insert_with(coord(3, 0), || 0).is_err());
+    }
+
+    #[test]
+    fn test_insert_many() {
+        let mut grid = HashGrid::<i32, i32>::new();
+        grid.insert_many((0..100).map(|i| (coord(i % 10, i / 10), i)))
+            .unwrap();
+        assert_eq!(grid.iter().count(), 100);
+        assert_eq!(grid.get(&coord(3, 4)), Some(&43));
+    }
+
+    #[test]
+    fn test_insert_many_out_of_bounds() {
+        let mut grid = HashGrid::<i32, i32>::new()
+            .set_max_x(2)
+            .set_strict_bounds(true);
+        let result = grid.insert_many(vec![(coord(0, 0), 1), (coord(3, 0), 2), (coord(1, 0), 3)]);
+        assert!(result.is_err());
+        assert_eq!(grid.iter().count(), 0);
+    }
 
     #[test]
     fn test_contains_key() {
@@ -168,6 +500,295 @@ mod tests {
         assert!(grid.contains_key(&c));
     }
 
+    #[test]
+    fn test_iter() {
+        let mut grid = HashGrid::<i32, i32>::new();
+        grid.insert(coord(2, 0), 3).unwrap();
+        grid.insert(coord(0, 1), 1).unwrap();
+        grid.insert(coord(1, 1), 2).unwrap();
+
+        let mut entries: Vec<_> = grid.iter().collect();
+        entries.sort();
+        assert_eq!(
+            entries,
+            vec![(coord(0, 1), 1), (coord(1, 1), 2), (coord(2, 0), 3)]
+        );
+
+        let mut keys: Vec<_> = grid.keys().collect();
+        keys.sort();
+        assert_eq!(keys, vec![coord(0, 1), coord(1, 1), coord(2, 0)]);
+
+        assert_eq!(grid.values().sum::<i32>(), 6);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut grid = HashGrid::<i32, i32>::new();
+        let c = coord(1, 1);
+        assert_eq!(grid.get_mut(&c), None);
+        grid.insert(c, 1).unwrap();
+        *grid.get_mut(&c).unwrap() += 5;
+        assert_eq!(grid.get(&c), Some(&6));
+    }
+
+    #[test]
+    fn test_count_matching() {
+        let mut grid = HashGrid::<i32, i32>::new();
+        grid.insert(coord(0, 0), 1).unwrap();
+        grid.insert(coord(1, 0), 2).unwrap();
+        grid.insert(coord(2, 0), 1).unwrap();
+        assert_eq!(grid.count_matching(1), 2);
+        assert_eq!(grid.count_matching(2), 1);
+        assert_eq!(grid.count_matching(0), 0);
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let mut grid = HashGrid::<i32, i32>::new();
+        assert_eq!(grid.dimensions(), None);
+        grid.insert(coord(-1, 2), 1).unwrap();
+        grid.insert(coord(3, 4), 1).unwrap();
+        assert_eq!(grid.dimensions(), Some((5, 3)));
+
+        let bounded = HashGrid::<i32, i32>::new()
+            .set_min_x(0)
+            .set_max_x(9)
+            .set_min_y(0)
+            .set_max_y(4);
+        assert_eq!(bounded.dimensions(), Some((10, 5)));
+    }
+
+    #[test]
+    fn test_find() {
+        let mut grid = HashGrid::<i32, char>::new();
+        grid.insert(coord(0, 0), '.').unwrap();
+        grid.insert(coord(3, 1), 'S').unwrap();
+        grid.insert(coord(1, 4), 'E').unwrap();
+        grid.insert(coord(2, 2), 'E').unwrap();
+        assert_eq!(grid.find('S'), Some(coord(3, 1)));
+        assert_eq!(grid.find('#'), None);
+
+        let mut ends = grid.find_all('E');
+        ends.sort();
+        assert_eq!(ends, vec![coord(1, 4), coord(2, 2)]);
+    }
+
+    #[test]
+    fn test_from_iter_and_extend() {
+        let mut grid: HashGrid<i32, i32> = vec![(coord(0, 0), 1), (coord(2, 3), 5)]
+            .into_iter()
+            .collect();
+        assert_eq!(grid.get(&coord(0, 0)), Some(&1));
+        assert_eq!(grid.get(&coord(2, 3)), Some(&5));
+
+        grid.extend(vec![(coord(0, 0), 2), (coord(4, 4), 9)]);
+        assert_eq!(grid.get(&coord(0, 0)), Some(&2));
+        assert_eq!(grid.get(&coord(4, 4)), Some(&9));
+        assert_eq!(grid.iter().count(), 3);
+    }
+
+    #[test]
+    fn test_values_mut() {
+        let mut grid = HashGrid::<i32, i32>::new();
+        grid.insert(coord(0, 0), 1).unwrap();
+        grid.insert(coord(5, 5), 4).unwrap();
+        grid.values_mut().for_each(|v| *v *= 2);
+        assert_eq!(grid.get(&coord(0, 0)), Some(&2));
+        assert_eq!(grid.get(&coord(5, 5)), Some(&8));
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let mut grid = HashGrid::<i32, i32>::new();
+        assert_eq!(grid.bounding_box(), None);
+        grid.insert(coord(2, 5), 1).unwrap();
+        assert_eq!(grid.bounding_box(), Some((coord(2, 5), coord(2, 5))));
+        grid.insert(coord(-3, 7), 1).unwrap();
+        grid.insert(coord(4, -1), 1).unwrap();
+        assert_eq!(grid.bounding_box(), Some((coord(-3, -1), coord(4, 7))));
+        grid.remove(&coord(4, -1));
+        assert_eq!(grid.bounding_box(), Some((coord(-3, 5), coord(2, 7))));
+    }
+
+    #[test]
+    fn test_render() {
+        let mut grid = HashGrid::<i32, bool>::new();
+        assert_eq!(grid.render('.', |_| '#'), "");
+        grid.insert(coord(-1, 0), true).unwrap();
+        grid.insert(coord(2, 0), false).unwrap();
+        grid.insert(coord(0, 2), true).unwrap();
+        let rendered = grid.render(' ', |alive| if *alive { '#' } else { '.' });
+        assert_eq!(rendered, "#  .\n    \n #  ");
+    }
+
+    #[test]
+    fn test_new_with_hasher() {
+        type StdHasher = std::hash::BuildHasherDefault<std::collections::hash_map::DefaultHasher>;
+
+        let mut grid = HashGrid::<i32, i32, StdHasher>::new_with_hasher(StdHasher::default());
+        grid.insert(coord(1, 2), 3).unwrap();
+        assert_eq!(grid.get(&coord(1, 2)), Some(&3));
+
+        let collected: HashGrid<i32, i32, StdHasher> = vec![(coord(0, 0), 1)].into_iter().collect();
+        assert_eq!(collected.get(&coord(0, 0)), Some(&1));
+    }
+
+    #[test]
+    fn test_translate() {
+        let mut grid = HashGrid::<u32, char>::new().set_max_x(5).set_max_y(5);
+        grid.insert(Coord::new(0, 0), 'a').unwrap();
+        grid.insert(Coord::new(2, 3), 'b').unwrap();
+        grid.translate(3, 1).unwrap();
+
+        assert_eq!(grid.get(&Coord::new(3, 1)), Some(&'a'));
+        assert_eq!(grid.get(&Coord::new(5, 4)), Some(&'b'));
+        assert_eq!(grid.get(&Coord::new(0, 0)), None);
+        assert_eq!(grid.iter().count(), 2);
+        assert!(grid.insert(Coord::new(8, 6), 'c').is_ok());
+    }
+
+    #[test]
+    fn test_translate_overflow() {
+        let mut grid = HashGrid::<u8, char>::new();
+        grid.insert(Coord::new(250, 0), 'a').unwrap();
+        assert!(grid.translate(10, 0).is_err());
+        assert_eq!(grid.get(&Coord::new(250, 0)), Some(&'a'));
+
+        let mut signed = HashGrid::<i32, char>::new();
+        signed.insert(coord(2, 2), 'a').unwrap();
+        signed.translate(-3, -1).unwrap();
+        assert_eq!(signed.get(&coord(-1, 1)), Some(&'a'));
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut grid = HashGrid::<i32, char>::new()
+            .set_max_x(5)
+            .set_strict_bounds(true);
+        grid.insert(coord(0, 0), 'a').unwrap();
+        grid.insert(coord(1, 0), 'b').unwrap();
+        grid.swap(&coord(0, 0), &coord(1, 0)).unwrap();
+        assert_eq!(grid.get(&coord(0, 0)), Some(&'b'));
+        assert_eq!(grid.get(&coord(1, 0)), Some(&'a'));
+
+        grid.swap(&coord(0, 0), &coord(3, 3)).unwrap();
+        assert_eq!(grid.get(&coord(0, 0)), None);
+        assert_eq!(grid.get(&coord(3, 3)), Some(&'b'));
+
+        grid.swap(&coord(4, 4), &coord(4, 5)).unwrap();
+        assert_eq!(grid.iter().count(), 2);
+
+        assert!(grid.swap(&coord(1, 0), &coord(6, 0)).is_err());
+        assert_eq!(grid.get(&coord(1, 0)), Some(&'a'));
+    }
+
+    #[test]
+    fn test_non_copy_values() {
+        let mut grid = HashGrid::<i32, String>::new();
+        let c = coord(1, 1);
+        grid.insert(c, "start".to_string()).unwrap();
+        grid.get_mut(&c).unwrap().push_str("-end");
+        assert_eq!(grid.get(&c).map(String::as_str), Some("start-end"));
+        assert_eq!(grid.down_n(&coord(1, 0), 1), Some("start-end".to_string()));
+        assert!(grid.matches(&c, "start-end".to_string()).unwrap());
+        assert_eq!(grid.remove(&c), Some("start-end".to_string()));
+        assert_eq!(grid.get(&c), None);
+    }
+
+    #[test]
+    fn test_fold_sorted() {
+        let mut grid = HashGrid::<i32, u32>::new();
+        grid.insert(Coord::new(1, 1), 4).unwrap();
+        grid.insert(Coord::new(0, 0), 1).unwrap();
+        grid.insert(Coord::new(5, 0), 2).unwrap();
+        grid.insert(Coord::new(-3, 1), 3).unwrap();
+
+        assert_eq!(grid.fold_sorted(0, |acc, _, v| acc + v), 10);
+        assert_eq!(grid.fold_sorted(0, |acc, _, v| acc * 10 + v), 1234);
+    }
+
+    #[test]
+    fn test_partial_eq() {
+        let mut a = HashGrid::<i32, char>::new();
+        a.insert(Coord::new(0, 0), '#').unwrap();
+        a.insert(Coord::new(3, -2), '.').unwrap();
+
+        let mut b = HashGrid::<i32, char>::new()
+            .set_min_x(-10)
+            .set_max_y(10)
+            .set_strict_bounds(true);
+        b.insert(Coord::new(3, -2), '.').unwrap();
+        b.insert(Coord::new(0, 0), '#').unwrap();
+        assert_eq!(a, b);
+
+        b.insert(Coord::new(0, 0), '.').unwrap();
+        assert_ne!(a, b);
+        b.insert(Coord::new(0, 0), '#').unwrap();
+        b.insert(Coord::new(1, 1), '#').unwrap();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_hash_ignores_insertion_order() {
+        let hash_of = |grid: &HashGrid<i32, u8>| {
+            let mut hasher = DefaultHasher::new();
+            grid.hash(&mut hasher);
+            hasher.finish()
+        };
+        let cells = [
+            (Coord::new(0, 0), 1),
+            (Coord::new(4, -2), 2),
+            (Coord::new(-7, 3), 3),
+        ];
+
+        let forward: HashGrid<i32, u8> = cells.into_iter().collect();
+        let backward: HashGrid<i32, u8> = cells.into_iter().rev().collect();
+        assert_eq!(forward, backward);
+        assert_eq!(hash_of(&forward), hash_of(&backward));
+
+        let mut changed = forward.clone();
+        changed.insert(Coord::new(0, 0), 9).unwrap();
+        assert_ne!(hash_of(&forward), hash_of(&changed));
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut base: HashGrid<i32, u32> = [(Coord::new(0, 0), 1), (Coord::new(1, 0), 2)]
+            .into_iter()
+            .collect();
+        let overlay: HashGrid<i32, u32> = [(Coord::new(1, 0), 10), (Coord::new(5, 5), 7)]
+            .into_iter()
+            .collect();
+
+        base.merge(&overlay, |a, b| a + b).unwrap();
+        assert_eq!(base.get(&Coord::new(0, 0)), Some(&1));
+        assert_eq!(base.get(&Coord::new(1, 0)), Some(&12));
+        assert_eq!(base.get(&Coord::new(5, 5)), Some(&7));
+        assert_eq!(base.len(), 3);
+
+        let mut bounded = HashGrid::<i32, u32>::new()
+            .set_max_x(2)
+            .set_strict_bounds(true);
+        bounded.insert(Coord::new(1, 0), 1).unwrap();
+        assert!(bounded.merge(&overlay, |a, b| a + b).is_err());
+        assert_eq!(bounded.get(&Coord::new(1, 0)), Some(&1));
+    }
+
+    #[test]
+    fn test_dimensions_overflow() {
+        let mut grid = HashGrid::<i16, bool>::new();
+        grid.insert(Coord::new(i16::MIN, 0), true).unwrap();
+        grid.insert(Coord::new(i16::MAX, 0), true).unwrap();
+        assert_eq!(grid.dimensions(), None);
+
+        let mut grid = HashGrid::<u8, bool>::new();
+        grid.insert(Coord::new(0, 3), true).unwrap();
+        grid.insert(Coord::new(254, 5), true).unwrap();
+        assert_eq!(grid.dimensions(), Some((255, 3)));
+        grid.insert(Coord::new(255, 5), true).unwrap();
+        assert_eq!(grid.dimensions(), None);
+    }
+
     #[test]
     fn test_clear() {
         let mut grid = HashGrid::<i32, i32>::new();
@@ -190,6 +811,20 @@ mod tests {
         assert!(grid.insert(out_bounds, 2).is_err());
     }
 
+    #[test]
+    fn test_strict_bounds() {
+        let mut grid = HashGrid::<i32, i32>::new()
+            .set_min_x(0)
+            .set_max_x(2)
+            .set_min_y(0)
+            .set_max_y(2)
+            .set_strict_bounds(true);
+        assert!(grid.insert(coord(1, 1), 1).is_ok());
+        assert!(grid.insert(coord(3, 1), 2).is_err());
+        assert!(grid.insert(coord(1, -1), 2).is_err());
+        assert_eq!(grid.get(&coord(3, 1)), None);
+    }
+
     #[test]
     fn test_up_n() {
         let mut grid = HashGrid::<i32, i32>::new();
@@ -200,6 +835,31 @@ mod tests {
         assert_eq!(grid.up_n(&c, 2), None);
     }
 
+    #[test]
+    fn test_down_left_right_n() {
+        let mut grid = HashGrid::<i32, i32>::new();
+        let c = coord(2, 2);
+        grid.insert(coord(2, 4), 1).unwrap();
+        grid.insert(coord(0, 2), 2).unwrap();
+        grid.insert(coord(3, 2), 3).unwrap();
+        assert_eq!(grid.down_n(&c, 2), Some(1));
+        assert_eq!(grid.left_n(&c, 2), Some(2));
+        assert_eq!(grid.right_n(&c, 1), Some(3));
+        assert_eq!(grid.right_n(&c, 2), None);
+    }
+
+    #[test]
+    fn test_n_bounds() {
+        let mut grid = HashGrid::<u32, i32>::new().set_max_x(3).set_max_y(3);
+        let c = Coord::new(1u32, 3u32);
+        grid.insert(Coord::new(1, 2), 5).unwrap();
+        assert_eq!(grid.up_n(&c, 1), Some(5));
+        assert_eq!(grid.up_n(&c, 4), None);
+        assert_eq!(grid.left_n(&c, 2), None);
+        assert_eq!(grid.right_n(&c, 3), None);
+        assert_eq!(grid.down_n(&c, 1), None);
+    }
+
     #[test]
     fn test_matches() {
         let mut grid = HashGrid::<i32, i32>::new();
@@ -210,4 +870,25 @@ mod tests {
         let missing = coord(1, 1);
         assert!(!grid.matches(&missing, 123).unwrap());
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let mut grid = HashGrid::<i32, char>::new()
+            .set_min_x(0)
+            .set_max_x(5)
+            .set_strict_bounds(true);
+        grid.insert(coord(1, 2), 'a').unwrap();
+        grid.insert(coord(3, -4), 'b').unwrap();
+
+        let json = serde_json::to_string(&grid).unwrap();
+        let restored: HashGrid<i32, char> = serde_json::from_str(&json).unwrap();
+
+        let mut expected: Vec<_> = grid.iter().collect();
+        let mut actual: Vec<_> = restored.iter().collect();
+        expected.sort();
+        actual.sort();
+        assert_eq!(actual, expected);
+        assert!(restored.check_bounds(&coord(6, 0)).is_err());
+    }
 }
@@ -1,22 +1,84 @@
 use anyhow::{Result, bail};
 use hashbrown::HashMap;
+use std::{
+    fmt::{self, Display},
+    hash::{Hash, Hasher},
+};
 
-use crate::{Coord, Grid, GridNum};
+use crate::{Connectivity, Coord, Grid, GridNum};
 
 #[derive(Debug, Clone, Default)]
-pub struct LinearGrid<T: GridNum, V: Copy> {
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "LinearGridRepr<V>",
+        into = "LinearGridRepr<V>",
+        bound(
+            serialize = "V: serde::Serialize",
+            deserialize = "V: serde::Deserialize<'de>"
+        )
+    )
+)]
+pub struct LinearGrid<T: GridNum, V: Clone> {
     _phantom: std::marker::PhantomData<T>,
     data: Vec<V>,
+    empty: V,
     width: usize,
     height: usize,
 }
 
-pub struct LinearGridIter<'a, T: GridNum, V: Copy> {
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct LinearGridRepr<V> {
+    width: usize,
+    height: usize,
+    data: Vec<V>,
+    empty: V,
+}
+
+#[cfg(feature = "serde")]
+impl<T: GridNum, V: Clone> From<LinearGrid<T, V>> for LinearGridRepr<V> {
+    fn from(grid: LinearGrid<T, V>) -> Self {
+        Self {
+            width: grid.width,
+            height: grid.height,
+            data: grid.data,
+            empty: grid.empty,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: GridNum, V: Clone> TryFrom<LinearGridRepr<V>> for LinearGrid<T, V> {
+    type Error = anyhow::Error;
+
+    fn try_from(repr: LinearGridRepr<V>) -> Result<Self> {
+        if repr.data.len() != repr.width * repr.height {
+            bail!(
+                "Data length {} does not match {}x{} grid",
+                repr.data.len(),
+                repr.width,
+                repr.height
+            );
+        }
+
+        Ok(Self {
+            _phantom: std::marker::PhantomData,
+            data: repr.data,
+            empty: repr.empty,
+            width: repr.width,
+            height: repr.height,
+        })
+    }
+}
+
+pub struct LinearGridIter<'a, T: GridNum, V: Clone> {
     grid: &'a LinearGrid<T, V>,
     index: usize,
 }
 
-impl<'a, T: GridNum + From<usize>, V: Copy> Iterator for LinearGridIter<'a, T, V> {
+impl<'a, T: GridNum, V: Clone> Iterator for LinearGridIter<'a, T, V> {
     type Item = (Coord<T>, V);
 
     fn next(&mut self) -> Option<Self::Item> {
@@ -27,50 +89,484 @@ impl<'a, T: GridNum + From<usize>, V: Copy> Iterator for LinearGridIter<'a, T, V
         let idx = self.index;
         self.index += 1;
 
-        let x = idx % self.grid.width;
-        let y = idx / self.grid.width;
+        let coord = self.grid.get_coord_from_index(idx)?;
+
+        Some((coord, self.grid.data[idx].clone()))
+    }
+}
+
+pub struct LinearGridIterMut<'a, T: GridNum, V: Clone> {
+    _phantom: std::marker::PhantomData<T>,
+    cells: std::iter::Enumerate<std::slice::IterMut<'a, V>>,
+    width: usize,
+}
+
+impl<'a, T: GridNum, V: Clone> Iterator for LinearGridIterMut<'a, T, V> {
+    type Item = (Coord<T>, &'a mut V);
 
-        Some((Coord(x.into(), y.into()), self.grid.data[idx]))
+    fn next(&mut self) -> Option<Self::Item> {
+        let (idx, value) = self.cells.next()?;
+
+        let x = T::try_from(idx % self.width).ok()?;
+        let y = T::try_from(idx / self.width).ok()?;
+
+        Some((Coord(x, y), value))
     }
 }
 
-impl<T: GridNum, V: Copy> LinearGrid<T, V> {
+impl<T: GridNum, V: Clone> LinearGrid<T, V> {
     pub fn iter(&self) -> LinearGridIter<'_, T, V> {
         LinearGridIter {
             grid: self,
             index: 0,
         }
     }
+
+    pub fn iter_mut(&mut self) -> LinearGridIterMut<'_, T, V> {
+        LinearGridIterMut {
+            _phantom: std::marker::PhantomData,
+            cells: self.data.iter_mut().enumerate(),
+            width: self.width,
+        }
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> + '_ {
+        self.data.iter_mut()
+    }
 }
 
-impl<T: GridNum, V: Copy> LinearGrid<T, V> {
+impl<T: GridNum, V: Clone> LinearGrid<T, V> {
     pub fn new(width: usize, height: usize, initial: V) -> Self {
         let capacity = width * height;
         Self {
             _phantom: std::marker::PhantomData,
-            data: vec![initial; capacity],
+            data: vec![initial.clone(); capacity],
+            empty: initial,
             width,
             height,
         }
     }
 
+    /// Builds a grid from equal-length rows. `empty` is the sentinel used by `clear`,
+    /// `remove` and `contains_key`.
+    pub fn from_2d_vec(rows: Vec<Vec<V>>, empty: V) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut data = Vec::with_capacity(width * height);
+
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                bail!("Row {y} has length {}, expected {width}", row.len());
+            }
+            data.extend(row);
+        }
+
+        Ok(Self {
+            _phantom: std::marker::PhantomData,
+            data,
+            empty,
+            width,
+            height,
+        })
+    }
+
+    pub fn from_str_with(input: &str, empty: V, f: impl Fn(char) -> V) -> Result<Self> {
+        Self::from_2d_vec(
+            input
+                .lines()
+                .map(|line| line.chars().map(&f).collect())
+                .collect(),
+            empty,
+        )
+    }
+
+    // builds a new grid by looking up each destination cell's source position
+    fn transformed(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self {
+        let data = (0..width * height)
+            .map(|idx| {
+                let (x, y) = source(idx % width, idx / width);
+                self.data[y * self.width + x].clone()
+            })
+            .collect();
+
+        Self {
+            _phantom: std::marker::PhantomData,
+            data,
+            empty: self.empty.clone(),
+            width,
+            height,
+        }
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        self.transformed(self.height, self.width, |x, y| (y, self.height - 1 - x))
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        self.transformed(self.height, self.width, |x, y| (self.width - 1 - y, x))
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        self.transformed(self.width, self.height, |x, y| {
+            (self.width - 1 - x, self.height - 1 - y)
+        })
+    }
+
+    pub fn transpose(&self) -> Self {
+        self.transformed(self.height, self.width, |x, y| (y, x))
+    }
+
+    pub fn flip_horizontal(&self) -> Self {
+        self.transformed(self.width, self.height, |x, y| (self.width - 1 - x, y))
+    }
+
+    pub fn flip_vertical(&self) -> Self {
+        self.transformed(self.width, self.height, |x, y| (x, self.height - 1 - y))
+    }
+
+    pub fn sub_grid(&self, top_left: Coord<T>, width: usize, height: usize) -> Result<Self> {
+        let (Ok(left), Ok(top)) = (
+            TryInto::<usize>::try_into(top_left.x()),
+            TryInto::<usize>::try_into(top_left.y()),
+        ) else {
+            bail!("Sub-grid origin is not a valid index");
+        };
+        let fits = |start: usize, len: usize, limit: usize| {
+            start.checked_add(len).is_some_and(|end| end <= limit)
+        };
+        if !fits(left, width, self.width) || !fits(top, height, self.height) {
+            bail!(
+                "Sub-grid {width}x{height} at ({left}, {top}) exceeds {}x{} grid",
+                self.width,
+                self.height
+            );
+        }
+
+        Ok(self.transformed(width, height, |x, y| (left + x, top + y)))
+    }
+
+    /// Cyclically shifts row `y` right by `by` cells.
+    pub fn rotate_row(&mut self, y: usize, by: usize) -> Result<()> {
+        if y >= self.height {
+            bail!("Row {y} is out of range for height {}", self.height);
+        }
+        self.data[y * self.width..(y + 1) * self.width].rotate_right(by % self.width.max(1));
+        Ok(())
+    }
+
+    /// Cyclically shifts column `x` down by `by` cells.
+    pub fn rotate_column(&mut self, x: usize, by: usize) -> Result<()> {
+        let Some(mut column) = self.column(x) else {
+            bail!("Column {x} is out of range for width {}", self.width);
+        };
+        column.rotate_right(by % self.height.max(1));
+        for (y, value) in column.into_iter().enumerate() {
+            self.data[y * self.width + x] = value;
+        }
+        Ok(())
+    }
+
+    /// Every `w`x`h` sub-grid in row-major order of its top-left corner.
+    pub fn windows(&self, w: usize, h: usize) -> impl Iterator<Item = (Coord<T>, Self)> + '_ {
+        let xs = self.width.checked_sub(w).map_or(0, |n| n + 1);
+        let ys = self.height.checked_sub(h).map_or(0, |n| n + 1);
+
+        (0..ys).flat_map(move |top| {
+            (0..xs).filter_map(move |left| {
+                let origin = Coord(T::try_from(left).ok()?, T::try_from(top).ok()?);
+                Some((origin, self.transformed(w, h, |x, y| (left + x, top + y))))
+            })
+        })
+    }
+
+    pub fn row(&self, y: usize) -> Option<&[V]> {
+        if y >= self.height {
+            return None;
+        }
+        self.data.get(y * self.width..(y + 1) * self.width)
+    }
+
+    pub fn column(&self, x: usize) -> Option<Vec<V>> {
+        if x >= self.width {
+            return None;
+        }
+        Some(
+            self.data
+                .iter()
+                .skip(x)
+                .step_by(self.width)
+                .cloned()
+                .collect(),
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn len(&self) -> usize {
+        Grid::len(self)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        Grid::is_empty(self)
+    }
+
+    pub fn map<U: Clone>(&self, f: impl Fn(&V) -> U) -> LinearGrid<T, U> {
+        LinearGrid {
+            _phantom: std::marker::PhantomData,
+            data: self.data.iter().map(&f).collect(),
+            empty: f(&self.empty),
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    pub fn contains_key(&self, key: &Coord<T>) -> bool
+    where
+        V: PartialEq,
+    {
+        self.get(key).is_some_and(|v| *v != self.empty)
+    }
+
+    pub fn resize(&mut self, new_width: usize, new_height: usize, fill: V) {
+        let mut data = vec![fill; new_width * new_height];
+        for y in 0..self.height.min(new_height) {
+            for x in 0..self.width.min(new_width) {
+                if let Some(value) = self.data.get(y * self.width + x) {
+                    data[y * new_width + x] = value.clone();
+                }
+            }
+        }
+
+        self.data = data;
+        self.width = new_width;
+        self.height = new_height;
+    }
+
+    pub fn to_2d_vec(&self) -> Vec<Vec<V>> {
+        if self.width == 0 {
+            return Vec::new();
+        }
+        self.data.chunks(self.width).map(<[V]>::to_vec).collect()
+    }
+
+    pub fn get_wrapped(&self, coord: &Coord<i64>) -> Option<&V> {
+        let width = i64::try_from(self.width).ok()?;
+        let height = i64::try_from(self.height).ok()?;
+        if width == 0 || height == 0 {
+            return None;
+        }
+        let x = coord.x().rem_euclid(width) as usize;
+        let y = coord.y().rem_euclid(height) as usize;
+
+        self.data.get(y * self.width + x)
+    }
+
+    /// Advances every cell one generation. The next states are written into `scratch`,
+    /// which is then swapped with the grid's storage, so passing the same buffer on
+    /// every call lets steady-state stepping run without allocating.
+    pub fn step_in_place(
+        &mut self,
+        rule: impl Fn(V, &[V]) -> V,
+        connectivity: Connectivity,
+        scratch: &mut Vec<V>,
+    ) {
+        let mut neighbors: [V; 8] = std::array::from_fn(|_| self.empty.clone());
+        scratch.clear();
+
+        for (idx, value) in self.data.iter().enumerate() {
+            let (x, y) = (idx % self.width, idx / self.width);
+            let mut count = 0;
+            for &(dx, dy) in connectivity.offsets() {
+                if let (Some(nx), Some(ny)) = (x.checked_add_signed(dx), y.checked_add_signed(dy))
+                    && nx < self.width
+                    && ny < self.height
+                {
+                    neighbors[count] = self.data[ny * self.width + nx].clone();
+                    count += 1;
+                }
+            }
+            scratch.push(rule(value.clone(), &neighbors[..count]));
+        }
+
+        std::mem::swap(&mut self.data, scratch);
+    }
+
+    pub fn sum(&self) -> V
+    where
+        V: std::ops::Add<Output = V> + Default,
+    {
+        self.data
+            .iter()
+            .cloned()
+            .fold(V::default(), |acc, value| acc + value)
+    }
+
+    pub fn count_if(&self, pred: impl Fn(&V) -> bool) -> usize {
+        self.data.iter().filter(|value| pred(value)).count()
+    }
+
+    /// Folds over the cells in row-major order.
+    pub fn fold<A>(&self, init: A, f: impl Fn(A, Coord<T>, &V) -> A) -> A {
+        self.data
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, value)| Some((self.get_coord_from_index(idx)?, value)))
+            .fold(init, |acc, (coord, value)| f(acc, coord, value))
+    }
+
+    // hands each row slice of the inclusive rectangle to `f`
+    fn for_each_region_row(
+        &mut self,
+        top_left: Coord<T>,
+        bottom_right: Coord<T>,
+        mut f: impl FnMut(&mut [V]),
+    ) -> Result<()> {
+        self.check_bounds(&top_left)?;
+        self.check_bounds(&bottom_right)?;
+        // both corners passed check_bounds, so they convert to in-range indices
+        let index = |n: T| TryInto::<usize>::try_into(n).ok().unwrap_or_default();
+        let (left, top) = (index(top_left.x()), index(top_left.y()));
+        let (right, bottom) = (index(bottom_right.x()), index(bottom_right.y()));
+        if left > right {
+            return Ok(());
+        }
+
+        for y in top..=bottom {
+            let row = y * self.width;
+            f(&mut self.data[row + left..=row + right]);
+        }
+        Ok(())
+    }
+
     pub fn get_index_from_coord(&self, coord: &Coord<T>) -> Option<usize> {
         let x: usize = coord.x().try_into().ok()?;
         let y: usize = coord.y().try_into().ok()?;
 
         Some(y * self.width + x)
     }
+
+    pub fn get_coord_from_index(&self, index: usize) -> Option<Coord<T>> {
+        if self.width == 0 {
+            return None;
+        }
+        let x = T::try_from(index % self.width).ok()?;
+        let y = T::try_from(index / self.width).ok()?;
+
+        Some(Coord(x, y))
+    }
+}
+
+impl<T: GridNum> LinearGrid<T, char> {
+    /// Parses one cell per character, using `'.'` as the empty sentinel: `remove`
+    /// and `clear` write `'.'`, and `contains_key` treats `'.'` cells as absent.
+    /// Use `from_str_with(input, sentinel, |c| c)` for a different sentinel.
+    pub fn from_str_chars(input: &str) -> Result<Self> {
+        Self::from_str_with(input, '.', |c| c)
+    }
+
+    /// The character at `coord`, or a space when out of range.
+    pub fn char_at(&self, coord: &Coord<T>) -> char {
+        self.get(coord).copied().unwrap_or(' ')
+    }
+}
+
+impl<T: GridNum> LinearGrid<T, u8> {
+    /// Parses a grid of decimal digits. The empty value is `u8::MAX` rather than 0 so
+    /// a parsed `0` still counts as present; `clear` and `remove` reset cells to it.
+    pub fn from_digit_grid(input: &str) -> Result<Self> {
+        let chars = LinearGrid::<T, char>::from_str_chars(input)?;
+        if let Some(c) = chars.data.iter().find(|c| !c.is_ascii_digit()) {
+            bail!("Invalid digit '{c}' in grid");
+        }
+
+        Ok(chars.map(|c| c.to_digit(10).map_or(u8::MAX, |d| d as u8)))
+    }
+}
+
+impl<T: GridNum> LinearGrid<T, bool> {
+    pub fn toggle_region(&mut self, top_left: Coord<T>, bottom_right: Coord<T>) -> Result<()> {
+        self.for_each_region_row(top_left, bottom_right, |row| {
+            row.iter_mut().for_each(|cell| *cell = !*cell)
+        })
+    }
 }
 
-impl<T: GridNum, V: Copy> Grid<T, V> for LinearGrid<T, V> {
+impl<T: GridNum, V: Clone + Into<char>> Display for LinearGrid<T, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.data.chunks(self.width.max(1)).enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", Into::<char>::into(cell.clone()))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+// `empty` is only the fill value for cleared cells, so it does not take part in equality
+impl<T: GridNum, V: Clone + PartialEq> PartialEq for LinearGrid<T, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height && self.data == other.data
+    }
+}
+
+impl<T: GridNum, V: Clone + Eq> Eq for LinearGrid<T, V> {}
+
+impl<T: GridNum, V: Clone + Hash> Hash for LinearGrid<T, V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.width.hash(state);
+        self.height.hash(state);
+        self.data.hash(state);
+    }
+}
+
+impl<T: GridNum, V: Clone> Grid<T, V> for LinearGrid<T, V> {
     fn clear(&mut self) {
-        self.data.clear();
+        self.data.fill(self.empty.clone());
     }
 
     fn check_bounds(&self, key: &Coord<T>) -> Result<()> {
-        // no need to check since already constrained
+        let Ok(x) = TryInto::<usize>::try_into(key.x()) else {
+            bail!("Key x-coordinate is not a valid index");
+        };
+        let Ok(y) = TryInto::<usize>::try_into(key.y()) else {
+            bail!("Key y-coordinate is not a valid index");
+        };
+        if x >= self.width {
+            bail!("Key x-coordinate is greater than grid width");
+        }
+        if y >= self.height {
+            bail!("Key y-coordinate is greater than grid height");
+        }
         Ok(())
     }
+    fn len(&self) -> usize {
+        self.width * self.height
+    }
+
+    fn fill_region(&mut self, top_left: Coord<T>, bottom_right: Coord<T>, value: V) -> Result<()> {
+        self.for_each_region_row(top_left, bottom_right, |row| row.fill(value.clone()))
+    }
+
+    fn dimensions(&self) -> Option<(T, T)> {
+        let width = T::try_from(self.width).ok()?;
+        let height = T::try_from(self.height).ok()?;
+        Some((width, height))
+    }
+
     fn insert(&mut self, key: Coord<T>, value: V) -> Result<()> {
         self.check_bounds(&key)?;
         let index = self
@@ -92,25 +588,109 @@ impl<T: GridNum, V: Copy> Grid<T, V> for LinearGrid<T, V> {
         self.data.get(index)
     }
 
-    // fn remove(&mut self, key: &Coord<T>) -> Option<V> {
-    //     self.check_bounds(key).ok()?;
-    //     self.data.remove(key)
-    // }
+    fn get_mut(&mut self, key: &Coord<T>) -> Option<&mut V> {
+        self.check_bounds(key).ok()?;
+        let index = self.get_index_from_coord(key)?;
+        self.data.get_mut(index)
+    }
+
+    fn remove(&mut self, key: &Coord<T>) -> Option<V> {
+        self.check_bounds(key).ok()?;
+        let index = self.get_index_from_coord(key)?;
+        let cell = self.data.get_mut(index)?;
+        Some(std::mem::replace(cell, self.empty.clone()))
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Coord<T>, V)> + '_> {
+        Box::new(LinearGrid::iter(self))
+    }
+
+    fn swap(&mut self, a: &Coord<T>, b: &Coord<T>) -> Result<()> {
+        self.check_bounds(a)?;
+        self.check_bounds(b)?;
+        let (Some(index_a), Some(index_b)) =
+            (self.get_index_from_coord(a), self.get_index_from_coord(b))
+        else {
+            bail!("Coordinate out of bounds");
+        };
+        self.data.swap(index_a, index_b);
+        Ok(())
+    }
+
+    fn count_matching(&self, value: V) -> usize
+    where
+        V: PartialEq,
+    {
+        self.data.iter().filter(|v| **v == value).count()
+    }
+
+    fn find(&self, value: V) -> Option<Coord<T>>
+    where
+        V: PartialEq,
+    {
+        let index = self.data.iter().position(|v| *v == value)?;
+        self.get_coord_from_index(index)
+    }
+
+    fn find_all(&self, value: V) -> Vec<Coord<T>>
+    where
+        V: PartialEq,
+    {
+        self.data
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| **v == value)
+            .filter_map(|(index, _)| self.get_coord_from_index(index))
+            .collect()
+    }
 
     fn up_n(&self, coord: &Coord<T>, step: T) -> Option<V> {
-        let new_coord = Coord::new(coord.x(), coord.y() - step);
+        let new_coord = coord.up_n(Some(step), None)?;
         self.check_bounds(&new_coord).ok()?;
 
         let index = self
             .get_index_from_coord(&new_coord)
             .ok_or_else(|| anyhow::anyhow!("Coordinate out of bounds"))
             .ok()?;
-        self.data.get(index).copied()
+        self.data.get(index).cloned()
+    }
+
+    fn down_n(&self, coord: &Coord<T>, step: T) -> Option<V> {
+        let new_coord = coord.down_n(Some(step), None)?;
+        self.check_bounds(&new_coord).ok()?;
+
+        let index = self
+            .get_index_from_coord(&new_coord)
+            .ok_or_else(|| anyhow::anyhow!("Coordinate out of bounds"))
+            .ok()?;
+        self.data.get(index).cloned()
+    }
+
+    fn left_n(&self, coord: &Coord<T>, step: T) -> Option<V> {
+        let new_coord = coord.left_n(Some(step), None)?;
+        self.check_bounds(&new_coord).ok()?;
+
+        let index = self
+            .get_index_from_coord(&new_coord)
+            .ok_or_else(|| anyhow::anyhow!("Coordinate out of bounds"))
+            .ok()?;
+        self.data.get(index).cloned()
+    }
+
+    fn right_n(&self, coord: &Coord<T>, step: T) -> Option<V> {
+        let new_coord = coord.right_n(Some(step), None)?;
+        self.check_bounds(&new_coord).ok()?;
+
+        let index = self
+            .get_index_from_coord(&new_coord)
+            .ok_or_else(|| anyhow::anyhow!("Coordinate out of bounds"))
+            .ok()?;
+        self.data.get(index).cloned()
     }
 
     fn matches(&self, key: &Coord<T>, value: V) -> Result<bool>
     where
-        V: PartialOrd,
+        V: PartialEq,
     {
         self.check_bounds(key)?;
         let index = self
@@ -139,14 +719,595 @@ mod tests {
         assert_eq!(grid.get(&c), Some(&42));
     }
 
-    // #[test]
-    // fn test_remove() {
-    //     let mut grid = LinearGrid::<i32, i32>::new(50, 50);
-    //     let c = coord(5, 6);
-    //     grid.insert(c, 7).unwrap();
-    //     assert_eq!(grid.remove(&c), Some(7));
-    //     assert_eq!(grid.get(&c), None);
-    // }
+    #[test]
+    fn test_remove() {
+        let mut grid = LinearGrid::<i32, i32>::new(50, 50, 0);
+        let c = coord(5, 6);
+        grid.insert(c, 7).unwrap();
+        assert_eq!(grid.remove(&c), Some(7));
+        assert_eq!(grid.get(&c), Some(&0));
+        assert_eq!(grid.remove(&c), Some(0));
+    }
+
+    #[test]
+    fn test_from_str_chars() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("abc\ndef\n").unwrap();
+        assert_eq!(grid.get(&coord(0, 0)), Some(&'a'));
+        assert_eq!(grid.get(&coord(2, 0)), Some(&'c'));
+        assert_eq!(grid.get(&coord(1, 1)), Some(&'e'));
+        assert_eq!(grid.get(&coord(0, 2)), None);
+        assert_eq!(grid.get(&coord(3, 0)), None);
+
+        let no_trailing = LinearGrid::<i32, char>::from_str_chars("abc\ndef").unwrap();
+        assert_eq!(no_trailing.get(&coord(2, 1)), Some(&'f'));
+    }
+
+    #[test]
+    fn test_from_str_chars_ragged() {
+        assert!(LinearGrid::<i32, char>::from_str_chars("abc\nde\n").is_err());
+    }
+
+    #[test]
+    fn test_display() {
+        let mut grid = LinearGrid::<i32, char>::new(3, 2, '.');
+        grid.insert(coord(0, 0), '#').unwrap();
+        grid.insert(coord(2, 1), '#').unwrap();
+        assert_eq!(grid.to_string(), "#..\n..#");
+
+        let input = "ab\ncd\nef";
+        let parsed = LinearGrid::<i32, char>::from_str_chars(input).unwrap();
+        assert_eq!(parsed.to_string(), input);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut grid = LinearGrid::<i32, i32>::new(3, 3, 0);
+        let c = coord(1, 1);
+        *grid.get_mut(&c).unwrap() += 5;
+        *grid.get_mut(&c).unwrap() += 1;
+        assert_eq!(grid.get(&c), Some(&6));
+        assert_eq!(grid.get_mut(&coord(3, 0)), None);
+    }
+
+    #[test]
+    fn test_from_2d_vec() {
+        let grid =
+            LinearGrid::<i32, i32>::from_2d_vec(vec![vec![1, 2, 3], vec![4, 5, 6]], 0).unwrap();
+        assert_eq!(grid.get(&coord(0, 0)), Some(&1));
+        assert_eq!(grid.get(&coord(2, 1)), Some(&6));
+        assert_eq!(grid.get(&coord(0, 2)), None);
+        assert_eq!(grid.get(&coord(3, 0)), None);
+    }
+
+    #[test]
+    fn test_from_2d_vec_ragged() {
+        assert!(LinearGrid::<i32, i32>::from_2d_vec(vec![vec![1, 2], vec![3]], 0).is_err());
+    }
+
+    #[test]
+    fn test_from_2d_vec_empty() {
+        let grid = LinearGrid::<i32, i32>::from_2d_vec(Vec::new(), 0).unwrap();
+        assert_eq!(grid.iter().count(), 0);
+        assert_eq!(grid.get(&coord(0, 0)), None);
+    }
+
+    #[test]
+    fn test_count_matching() {
+        let mut grid = LinearGrid::<i32, i32>::new(3, 3, 0);
+        grid.insert(coord(0, 0), 1).unwrap();
+        grid.insert(coord(1, 0), 2).unwrap();
+        grid.insert(coord(2, 2), 1).unwrap();
+        assert_eq!(grid.count_matching(1), 2);
+        assert_eq!(grid.count_matching(2), 1);
+        assert_eq!(grid.count_matching(0), 6);
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let grid = LinearGrid::<i32, i32>::new(4, 3, 0);
+        assert_eq!(grid.dimensions(), Some((4, 3)));
+        let too_big = LinearGrid::<u8, bool>::new(300, 1, false);
+        assert_eq!(too_big.dimensions(), None);
+    }
+
+    #[test]
+    fn test_rotate() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("abc\ndef").unwrap();
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_180().to_string(), "fed\ncba");
+        assert_eq!(grid.rotate_cw().get(&coord(1, 2)), Some(&'c'));
+
+        let full_turn = grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+        assert_eq!(full_turn.to_string(), grid.to_string());
+        assert_eq!(grid.rotate_cw().rotate_ccw().to_string(), grid.to_string());
+    }
+
+    #[test]
+    fn test_transpose() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("abc\ndef").unwrap();
+        let transposed = grid.transpose();
+        assert_eq!(transposed.to_string(), "ad\nbe\ncf");
+        assert_eq!(transposed.get(&coord(1, 2)), Some(&'f'));
+        assert_eq!(transposed.get(&coord(2, 0)), None);
+        assert_eq!(transposed.transpose().to_string(), grid.to_string());
+    }
+
+    #[test]
+    fn test_flip() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("abc\ndef").unwrap();
+        assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+        assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+        assert_eq!(grid.flip_horizontal().get(&coord(0, 1)), Some(&'f'));
+        assert_eq!(
+            grid.flip_horizontal().flip_horizontal().to_string(),
+            grid.to_string()
+        );
+        assert_eq!(
+            grid.flip_vertical().flip_vertical().to_string(),
+            grid.to_string()
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("..S\n.E.\nE..").unwrap();
+        assert_eq!(grid.find('S'), Some(coord(2, 0)));
+        assert_eq!(grid.find('E'), Some(coord(1, 1)));
+        assert_eq!(grid.find('#'), None);
+        assert_eq!(grid.find_all('E'), vec![coord(1, 1), coord(0, 2)]);
+    }
+
+    #[test]
+    fn test_values_mut() {
+        let mut grid =
+            LinearGrid::<i32, i32>::from_2d_vec(vec![vec![1, 2], vec![3, 4]], 0).unwrap();
+        for v in grid.values_mut() {
+            *v *= 2;
+        }
+        assert_eq!(grid.get(&coord(0, 0)), Some(&2));
+        assert_eq!(grid.get(&coord(1, 0)), Some(&4));
+        assert_eq!(grid.get(&coord(0, 1)), Some(&6));
+        assert_eq!(grid.get(&coord(1, 1)), Some(&8));
+    }
+
+    #[test]
+    fn test_sub_grid() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("abcd\nefgh\nijkl").unwrap();
+        let sub = grid.sub_grid(coord(1, 1), 2, 2).unwrap();
+        assert_eq!(sub.to_string(), "fg\njk");
+        assert_eq!(sub.dimensions(), Some((2, 2)));
+        assert_eq!(
+            grid.sub_grid(coord(0, 0), 4, 3).unwrap().to_string(),
+            grid.to_string()
+        );
+    }
+
+    #[test]
+    fn test_sub_grid_out_of_bounds() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("abcd\nefgh\nijkl").unwrap();
+        assert!(grid.sub_grid(coord(3, 0), 2, 1).is_err());
+        assert!(grid.sub_grid(coord(0, 2), 1, 2).is_err());
+        assert!(grid.sub_grid(coord(-1, 0), 1, 1).is_err());
+        assert!(grid.sub_grid(coord(1, 0), usize::MAX, 1).is_err());
+        assert!(grid.sub_grid(coord(0, 1), 1, usize::MAX).is_err());
+    }
+
+    #[test]
+    fn test_row_and_column() {
+        let grid = LinearGrid::<i32, i32>::from_2d_vec(
+            vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]],
+            0,
+        )
+        .unwrap();
+        assert_eq!(grid.row(1), Some(&[4, 5, 6][..]));
+        assert_eq!(grid.row(3), None);
+        assert_eq!(grid.column(2), Some(vec![3, 6, 9]));
+        assert_eq!(grid.column(3), None);
+    }
+
+    #[test]
+    fn test_size_accessors() {
+        let grid = LinearGrid::<i32, i32>::new(4, 3, 0);
+        assert_eq!(grid.width(), 4);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.len(), 12);
+        assert!(!grid.is_empty());
+        assert!(LinearGrid::<i32, i32>::new(0, 0, 0).is_empty());
+    }
+
+    #[test]
+    fn test_map() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("123\n456").unwrap();
+        let digits = grid.map(|c| c.to_digit(10).unwrap_or(0));
+        assert_eq!(digits.dimensions(), Some((3, 2)));
+        assert_eq!(digits.get(&coord(0, 0)), Some(&1));
+        assert_eq!(digits.get(&coord(2, 1)), Some(&6));
+    }
+
+    #[test]
+    fn test_from_digit_grid() {
+        let grid =
+            LinearGrid::<i32, u8>::from_digit_grid("21999\n39878\n98567\n87678\n98965\n").unwrap();
+        assert_eq!(grid.dimensions(), Some((5, 5)));
+        assert_eq!(grid.get(&coord(0, 0)), Some(&2));
+        assert_eq!(grid.get(&coord(1, 0)), Some(&1));
+        assert_eq!(grid.get(&coord(4, 4)), Some(&5));
+        assert_eq!(grid.get(&coord(2, 2)), Some(&5));
+
+        let mut zeros = LinearGrid::<i32, u8>::from_digit_grid("01\n23").unwrap();
+        assert!(zeros.contains_key(&coord(0, 0)));
+        assert_eq!(zeros.remove(&coord(0, 0)), Some(0));
+        assert!(!zeros.contains_key(&coord(0, 0)));
+        assert_eq!(zeros.count_matching(0), 0);
+    }
+
+    #[test]
+    fn test_from_digit_grid_invalid() {
+        assert!(LinearGrid::<i32, u8>::from_digit_grid("123\n4a6\n").is_err());
+        assert!(LinearGrid::<i32, u8>::from_digit_grid("123\n45\n").is_err());
+    }
+
+    #[test]
+    fn test_contains_key() {
+        let mut grid = LinearGrid::<i32, i32>::new(3, 3, 0);
+        let c = coord(1, 1);
+        assert!(!grid.contains_key(&c));
+        grid.insert(c, 5).unwrap();
+        assert!(grid.contains_key(&c));
+        assert!(!grid.contains_key(&coord(0, 1)));
+        assert!(!grid.contains_key(&coord(3, 1)));
+        grid.remove(&c);
+        assert!(!grid.contains_key(&c));
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut grid = LinearGrid::<i32, char>::from_str_chars("ab\ncd").unwrap();
+        grid.resize(4, 4, '.');
+        assert_eq!(grid.to_string(), "ab..\ncd..\n....\n....");
+        assert_eq!(grid.dimensions(), Some((4, 4)));
+
+        grid.insert(coord(3, 3), 'z').unwrap();
+        grid.resize(2, 2, '.');
+        assert_eq!(grid.to_string(), "ab\ncd");
+        assert_eq!(grid.get(&coord(3, 3)), None);
+
+        grid.resize(3, 1, '#');
+        assert_eq!(grid.to_string(), "ab#");
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut grid = LinearGrid::<i32, char>::from_str_chars("ab\ncd").unwrap();
+        grid.swap(&coord(0, 0), &coord(1, 1)).unwrap();
+        assert_eq!(grid.to_string(), "db\nca");
+        assert!(grid.swap(&coord(0, 0), &coord(2, 0)).is_err());
+        assert_eq!(grid.to_string(), "db\nca");
+    }
+
+    #[test]
+    fn test_after_clear() {
+        type Op = fn(&mut LinearGrid<i32, char>) -> String;
+        let cases: [(&str, Op, &str); 12] = [
+            ("rotate_cw", |g| g.rotate_cw().to_string(), ".#\n..\n.."),
+            ("rotate_ccw", |g| g.rotate_ccw().to_string(), "..\n..\n#."),
+            ("rotate_180", |g| g.rotate_180().to_string(), "...\n..#"),
+            ("transpose", |g| g.transpose().to_string(), "#.\n..\n.."),
+            (
+                "flip_horizontal",
+                |g| g.flip_horizontal().to_string(),
+                "..#\n...",
+            ),
+            (
+                "flip_vertical",
+                |g| g.flip_vertical().to_string(),
+                "...\n#..",
+            ),
+            (
+                "sub_grid",
+                |g| g.sub_grid(coord(0, 0), 2, 1).unwrap().to_string(),
+                "#.",
+            ),
+            ("windows", |g| g.windows(2, 2).count().to_string(), "2"),
+            (
+                "swap",
+                |g| {
+                    g.swap(&coord(0, 0), &coord(1, 1)).unwrap();
+                    g.to_string()
+                },
+                "...\n.#.",
+            ),
+            (
+                "rotate_row",
+                |g| {
+                    g.rotate_row(0, 1).unwrap();
+                    g.to_string()
+                },
+                ".#.\n...",
+            ),
+            (
+                "rotate_column",
+                |g| {
+                    g.rotate_column(0, 1).unwrap();
+                    g.to_string()
+                },
+                "...\n#..",
+            ),
+            (
+                "fill_region",
+                |g| {
+                    g.fill_region(coord(1, 0), coord(2, 1), '#').unwrap();
+                    g.to_string()
+                },
+                "###\n.##",
+            ),
+        ];
+        for (name, op, expected) in cases {
+            let mut grid = LinearGrid::<i32, char>::from_str_chars("ab#\ncd#").unwrap();
+            grid.clear();
+            grid.insert(coord(0, 0), '#').unwrap();
+            assert_eq!(op(&mut grid), expected, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut grid = LinearGrid::<i32, i32>::new(3, 2, 0);
+        for (c, v) in grid.iter_mut() {
+            *v = c.x() + c.y();
+        }
+        assert_eq!(grid.get(&coord(0, 0)), Some(&0));
+        assert_eq!(grid.get(&coord(2, 0)), Some(&2));
+        assert_eq!(grid.get(&coord(1, 1)), Some(&2));
+        assert_eq!(grid.get(&coord(2, 1)), Some(&3));
+    }
+
+    #[test]
+    fn test_non_copy_values() {
+        let mut grid = LinearGrid::<i32, Vec<u8>>::new(2, 2, Vec::new());
+        let c = coord(1, 0);
+        grid.get_mut(&c).unwrap().push(3);
+        grid.get_mut(&c).unwrap().push(4);
+        assert_eq!(grid.get(&c), Some(&vec![3, 4]));
+        assert_eq!(grid.remove(&c), Some(vec![3, 4]));
+        assert_eq!(grid.get(&c), Some(&Vec::new()));
+    }
+
+    #[test]
+    fn test_to_2d_vec() {
+        let rows = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        let grid = LinearGrid::<i32, i32>::from_2d_vec(rows.clone(), 0).unwrap();
+        assert_eq!(grid.to_2d_vec(), rows);
+        assert_eq!(
+            grid.transpose().to_2d_vec(),
+            vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+        );
+
+        let empty = LinearGrid::<i32, i32>::from_2d_vec(Vec::new(), 0).unwrap();
+        assert!(empty.to_2d_vec().is_empty());
+    }
+
+    #[test]
+    fn test_get_wrapped() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("abc\ndef").unwrap();
+        assert_eq!(grid.get_wrapped(&Coord::new(1, 1)), Some(&'e'));
+        assert_eq!(grid.get_wrapped(&Coord::new(301, 41)), Some(&'e'));
+        assert_eq!(grid.get_wrapped(&Coord::new(-1, -1)), Some(&'f'));
+        assert_eq!(grid.get_wrapped(&Coord::new(-300, 7)), Some(&'d'));
+        assert_eq!(grid.get_wrapped(&Coord::new(5, -4)), Some(&'c'));
+
+        let empty = LinearGrid::<i32, char>::new(0, 0, '.');
+        assert_eq!(empty.get_wrapped(&Coord::new(0, 0)), None);
+    }
+
+    #[test]
+    fn test_from_str_with_custom_cells() {
+        #[derive(Debug, Clone, PartialEq)]
+        enum Tile {
+            Open,
+            Wall,
+            Start,
+        }
+
+        let mut grid = LinearGrid::<i32, Tile>::from_str_with("S.\n#.", Tile::Open, |c| match c {
+            '#' => Tile::Wall,
+            'S' => Tile::Start,
+            _ => Tile::Open,
+        })
+        .unwrap();
+        assert_eq!(grid.find(Tile::Start), Some(coord(0, 0)));
+        assert!(grid.contains_key(&coord(0, 1)));
+        assert!(!grid.contains_key(&coord(1, 1)));
+        assert_eq!(grid.remove(&coord(0, 1)), Some(Tile::Wall));
+        assert_eq!(grid.get(&coord(0, 1)), Some(&Tile::Open));
+    }
+
+    #[test]
+    fn test_from_str_chars_sentinel() {
+        let mut dots = LinearGrid::<i32, char>::from_str_chars("#.").unwrap();
+        assert!(!dots.contains_key(&coord(1, 0)));
+        dots.clear();
+        assert_eq!(dots.to_string(), "..");
+
+        let spaces = LinearGrid::<i32, char>::from_str_with("#.", ' ', |c| c).unwrap();
+        assert!(spaces.contains_key(&coord(1, 0)));
+    }
+
+    #[test]
+    fn test_from_str_with() {
+        let grid = LinearGrid::<i32, bool>::from_str_with("#.#\n.#.", false, |c| c == '#').unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.get(&Coord::new(0, 0)), Some(&true));
+        assert_eq!(grid.get(&Coord::new(1, 0)), Some(&false));
+        assert_eq!(grid.get(&Coord::new(1, 1)), Some(&true));
+        assert_eq!(grid.count_matching(true), 3);
+
+        assert!(LinearGrid::<i32, bool>::from_str_with("#.#\n.#", false, |c| c == '#').is_err());
+    }
+
+    #[test]
+    fn test_step_in_place_blinker() {
+        let original = ".....\n..#..\n..#..\n..#..\n.....";
+        let mut grid = LinearGrid::<i32, char>::from_str_chars(original).unwrap();
+        let conway = |cell: char, neighbors: &[char]| match (
+            cell,
+            neighbors.iter().filter(|n| **n == '#').count(),
+        ) {
+            ('#', 2) | (_, 3) => '#',
+            _ => '.',
+        };
+        let mut scratch = Vec::new();
+
+        grid.step_in_place(conway, Connectivity::Eight, &mut scratch);
+        assert_eq!(grid.to_string(), ".....\n.....\n.###.\n.....\n.....");
+
+        // the two buffers just trade places from here on
+        let buffers = [grid.data.as_ptr(), scratch.as_ptr()];
+        grid.step_in_place(conway, Connectivity::Eight, &mut scratch);
+        assert_eq!(grid.to_string(), original);
+        assert_eq!([scratch.as_ptr(), grid.data.as_ptr()], buffers);
+
+        grid.step_in_place(conway, Connectivity::Eight, &mut scratch);
+        assert_eq!(grid.data.as_ptr(), buffers[0]);
+    }
+
+    #[test]
+    fn test_sum_and_count_if() {
+        let digits = LinearGrid::<i32, u8>::from_digit_grid("2199\n3987\n9856").unwrap();
+        assert_eq!(digits.sum(), 76);
+        assert_eq!(digits.count_if(|d| *d >= 8), 6);
+
+        let lit = LinearGrid::<i32, bool>::from_str_with("#.#\n.#.", false, |c| c == '#').unwrap();
+        assert_eq!(lit.count_if(|on| *on), 3);
+        assert_eq!(LinearGrid::<i32, u32>::new(0, 0, 0).sum(), 0);
+    }
+
+    #[test]
+    fn test_fold() {
+        let grid = LinearGrid::<i32, u8>::from_digit_grid("12\n34").unwrap();
+        assert_eq!(grid.fold(0u32, |acc, _, v| acc + u32::from(*v)), 10);
+
+        // order-sensitive: appends digits in row-major order
+        let digits = grid.fold(0u32, |acc, _, v| acc * 10 + u32::from(*v));
+        assert_eq!(digits, 1234);
+
+        let weighted = grid.fold(0, |acc, c, v| acc + (c.x() + 1) * i32::from(*v));
+        assert_eq!(weighted, 1 + 4 + 3 + 8);
+    }
+
+    #[test]
+    fn test_windows() {
+        let grid = LinearGrid::<i32, u8>::from_digit_grid("1234\n5678\n9012\n3456").unwrap();
+        let windows: Vec<_> = grid.windows(2, 2).collect();
+        assert_eq!(windows.len(), 9);
+        assert_eq!(windows[0].0, Coord::new(0, 0));
+        assert_eq!(windows[0].1.to_2d_vec(), vec![vec![1, 2], vec![5, 6]]);
+        assert_eq!(windows[5].0, Coord::new(2, 1));
+        assert_eq!(windows[5].1.to_2d_vec(), vec![vec![7, 8], vec![1, 2]]);
+
+        assert_eq!(grid.windows(4, 4).count(), 1);
+        assert_eq!(grid.windows(5, 1).count(), 0);
+        assert_eq!(grid.windows(4, 1).count(), 4);
+    }
+
+    #[test]
+    fn test_partial_eq() {
+        let a = LinearGrid::<i32, char>::from_str_chars("#.\n.#").unwrap();
+        let mut b = LinearGrid::<i32, char>::from_str_chars("#.\n.#").unwrap();
+        assert_eq!(a, b);
+
+        b.insert(Coord::new(1, 0), '#').unwrap();
+        assert_ne!(a, b);
+
+        // same cells in the same order, different shape
+        let wide = LinearGrid::<i32, char>::from_str_chars("#..#").unwrap();
+        assert_ne!(wide, a);
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::hash::DefaultHasher;
+
+        let hash_of = |grid: &LinearGrid<i32, char>| {
+            let mut hasher = DefaultHasher::new();
+            grid.hash(&mut hasher);
+            hasher.finish()
+        };
+        let a = LinearGrid::<i32, char>::from_str_chars("#.\n.#").unwrap();
+        let b = LinearGrid::<i32, char>::from_str_chars("#.\n.#").unwrap();
+        assert_eq!(hash_of(&a), hash_of(&b));
+        assert_ne!(hash_of(&a), hash_of(&a.flip_horizontal()));
+    }
+
+    #[test]
+    fn test_rotate_row_and_column() {
+        let mut screen =
+            LinearGrid::<i32, char>::from_str_chars("###....\n###....\n.......").unwrap();
+
+        screen.rotate_column(1, 1).unwrap();
+        assert_eq!(screen.to_string(), "#.#....\n###....\n.#.....");
+        screen.rotate_row(0, 4).unwrap();
+        assert_eq!(screen.to_string(), "....#.#\n###....\n.#.....");
+        screen.rotate_column(1, 1).unwrap();
+        assert_eq!(screen.to_string(), ".#..#.#\n#.#....\n.#.....");
+
+        // full turns are no-ops
+        screen.rotate_row(1, 14).unwrap();
+        screen.rotate_column(4, 6).unwrap();
+        assert_eq!(screen.to_string(), ".#..#.#\n#.#....\n.#.....");
+
+        assert!(screen.rotate_row(3, 1).is_err());
+        assert!(screen.rotate_column(7, 1).is_err());
+    }
+
+    #[test]
+    fn test_char_at() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("ab\ncd").unwrap();
+        assert_eq!(grid.char_at(&Coord::new(0, 0)), 'a');
+        assert_eq!(grid.char_at(&Coord::new(1, 1)), 'd');
+        assert_eq!(grid.char_at(&Coord::new(2, 0)), ' ');
+        assert_eq!(grid.char_at(&Coord::new(0, -1)), ' ');
+    }
+
+    #[test]
+    fn test_toggle_region() {
+        let mut lights = LinearGrid::<i32, bool>::new(5, 5, false);
+        lights
+            .fill_region(Coord::new(0, 0), Coord::new(4, 0), true)
+            .unwrap();
+        let original = lights.clone();
+
+        lights
+            .toggle_region(Coord::new(1, 0), Coord::new(3, 2))
+            .unwrap();
+        assert_eq!(lights.count_matching(true), 2 + 6);
+        lights
+            .toggle_region(Coord::new(2, 0), Coord::new(4, 4))
+            .unwrap();
+        lights
+            .toggle_region(Coord::new(2, 0), Coord::new(4, 4))
+            .unwrap();
+        lights
+            .toggle_region(Coord::new(1, 0), Coord::new(3, 2))
+            .unwrap();
+        assert_eq!(lights, original);
+
+        assert!(
+            lights
+                .toggle_region(Coord::new(0, 0), Coord::new(5, 0))
+                .is_err()
+        );
+        assert_eq!(lights, original);
+
+        let mut cleared = LinearGrid::<u8, bool>::new(2, 2, false);
+        cleared.clear();
+        cleared
+            .toggle_region(Coord::new(0, 1), Coord::new(1, 1))
+            .unwrap();
+        assert_eq!(cleared.count_matching(true), 2);
+    }
 
     #[test]
     fn test_clear() {
@@ -154,17 +1315,21 @@ mod tests {
         let c = coord(9, 10);
         grid.insert(c, 5).unwrap();
         grid.clear();
-        assert_eq!(grid.get(&c), None);
+        assert_eq!(grid.get(&c), Some(&0));
+        assert_eq!(grid.len(), 2500);
     }
 
-    // To bounds checking in linear grid insert
     #[test]
     fn test_bounds() {
         let mut grid = LinearGrid::<i32, i32>::new(3, 3, 0);
         let in_bounds = coord(1, 1);
         let out_bounds = coord(3, 1);
         assert!(grid.insert(in_bounds, 1).is_ok());
-        assert!(grid.insert(out_bounds, 2).is_ok());
+        assert!(grid.insert(out_bounds, 2).is_err());
+        assert!(grid.insert(coord(1, 3), 2).is_err());
+        assert!(grid.insert(coord(-1, 0), 2).is_err());
+        assert_eq!(grid.get(&coord(0, 2)), Some(&0));
+        assert_eq!(grid.get(&out_bounds), None);
     }
 
     #[test]
@@ -177,6 +1342,28 @@ mod tests {
         assert_eq!(grid.up_n(&c, 2), Some(0));
     }
 
+    #[test]
+    fn test_down_left_right_n() {
+        let mut grid = LinearGrid::<i32, i32>::new(5, 5, 0);
+        let c = coord(2, 2);
+        grid.insert(coord(2, 4), 1).unwrap();
+        grid.insert(coord(0, 2), 2).unwrap();
+        grid.insert(coord(3, 2), 3).unwrap();
+        assert_eq!(grid.down_n(&c, 2), Some(1));
+        assert_eq!(grid.left_n(&c, 2), Some(2));
+        assert_eq!(grid.right_n(&c, 1), Some(3));
+    }
+
+    #[test]
+    fn test_n_underflow() {
+        let grid = LinearGrid::<u32, i32>::new(5, 5, 0);
+        let c = Coord::new(1u32, 1u32);
+        assert_eq!(grid.up_n(&c, 2), None);
+        assert_eq!(grid.left_n(&c, 2), None);
+        assert_eq!(grid.up_n(&c, 1), Some(0));
+        assert_eq!(grid.left_n(&c, 1), Some(0));
+    }
+
     #[test]
     fn test_matches() {
         let mut grid = LinearGrid::<i32, i32>::new(5, 5, 0);
@@ -187,4 +1374,21 @@ mod tests {
         let missing = coord(1, 1);
         assert!(!grid.matches(&missing, 123).unwrap());
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("abc\ndef").unwrap();
+        let json = serde_json::to_string(&grid).unwrap();
+        let restored: LinearGrid<i32, char> = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.to_string(), grid.to_string());
+        assert_eq!(restored.dimensions(), Some((3, 2)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_rejects_mismatched_data() {
+        let json = r#"{"width":2,"height":2,"data":[1,2,3],"empty":0}"#;
+        assert!(serde_json::from_str::<LinearGrid<i32, i32>>(json).is_err());
+    }
 }
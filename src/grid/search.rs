@@ -0,0 +1,546 @@
+use hashbrown::{HashMap, HashSet};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, VecDeque},
+    hash::BuildHasher,
+};
+
+use crate::{Coord, Grid, GridNum, HashGrid};
+
+fn orthogonal_neighbors<T: GridNum>(
+    coord: &Coord<T>,
+    bounds: &(Coord<T>, Coord<T>),
+) -> Vec<Coord<T>> {
+    let (top_left, bottom_right) = bounds;
+    coord.udlr([
+        top_left.y(),
+        bottom_right.y(),
+        top_left.x(),
+        bottom_right.x(),
+    ])
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    pub fn neighbors<T: GridNum>(
+        self,
+        coord: &Coord<T>,
+        bounds: Option<(Coord<T>, Coord<T>)>,
+    ) -> Vec<Coord<T>> {
+        match (self, bounds) {
+            (Connectivity::Four, Some(bounds)) => orthogonal_neighbors(coord, &bounds),
+            (Connectivity::Four, None) => [
+                coord.up(None),
+                coord.down(None),
+                coord.left(None),
+                coord.right(None),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+            (Connectivity::Eight, bounds) => coord.neighbors8(bounds),
+        }
+    }
+
+    pub(crate) fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &[(0, -1), (1, 0), (0, 1), (-1, 0)],
+            Connectivity::Eight => &[
+                (-1, -1),
+                (0, -1),
+                (1, -1),
+                (-1, 0),
+                (1, 0),
+                (-1, 1),
+                (0, 1),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+pub fn flood_fill<T: GridNum, V: Clone + PartialEq, G: Grid<T, V>>(
+    grid: &G,
+    start: Coord<T>,
+    bounds: (Coord<T>, Coord<T>),
+    connectivity: Connectivity,
+) -> Vec<Coord<T>> {
+    let mut region = Vec::new();
+    flood_visit(grid, start, bounds, connectivity, |coord| {
+        region.push(coord)
+    });
+    region
+}
+
+pub fn flood_fill_count<T: GridNum, V: Clone + PartialEq, G: Grid<T, V>>(
+    grid: &G,
+    start: Coord<T>,
+    bounds: (Coord<T>, Coord<T>),
+    connectivity: Connectivity,
+) -> usize {
+    let mut count = 0;
+    flood_visit(grid, start, bounds, connectivity, |_| count += 1);
+    count
+}
+
+// BFS over the cells connected to `start` that share its value, calling `visit` on each
+fn flood_visit<T: GridNum, V: Clone + PartialEq, G: Grid<T, V>>(
+    grid: &G,
+    start: Coord<T>,
+    bounds: (Coord<T>, Coord<T>),
+    connectivity: Connectivity,
+    mut visit: impl FnMut(Coord<T>),
+) {
+    let Some(value) = grid.get(&start).cloned() else {
+        return;
+    };
+    if !start.range_contains(&bounds.0, &bounds.1) {
+        return;
+    }
+
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+
+    visited.insert(start);
+    queue.push_back(start);
+
+    while let Some(current) = queue.pop_front() {
+        visit(current);
+        for neighbor in connectivity.neighbors(&current, Some(bounds)) {
+            if grid.matches(&neighbor, value.clone()).unwrap_or(false) && visited.insert(neighbor) {
+                queue.push_back(neighbor);
+            }
+        }
+    }
+}
+
+pub fn connected_components<T: GridNum, V: Clone, G: Grid<T, V>>(
+    grid: &G,
+    bounds: (Coord<T>, Coord<T>),
+    connectivity: Connectivity,
+    same: impl Fn(&V, &V) -> bool,
+) -> Vec<Vec<Coord<T>>> {
+    let mut visited = HashSet::new();
+    let mut regions = Vec::new();
+
+    for (start, _) in grid.iter() {
+        if !start.range_contains(&bounds.0, &bounds.1) || !visited.insert(start) {
+            continue;
+        }
+
+        let mut region = Vec::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(current) = queue.pop_front() {
+            region.push(current);
+            let Some(value) = grid.get(&current) else {
+                continue;
+            };
+            for neighbor in connectivity.neighbors(&current, Some(bounds)) {
+                if grid.get(&neighbor).is_some_and(|other| same(value, other))
+                    && visited.insert(neighbor)
+                {
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        regions.push(region);
+    }
+
+    regions
+}
+
+/// Counts the cell edges of `region` that do not border another region cell.
+pub fn region_perimeter<T: GridNum, S: BuildHasher>(region: &HashSet<Coord<T>, S>) -> usize {
+    // neighbors that underflow the coordinate type can never be in the region, so
+    // counting shared edges is safer than counting missing neighbors directly
+    region
+        .iter()
+        .map(|cell| {
+            let shared = Connectivity::Four
+                .neighbors(cell, None)
+                .iter()
+                .filter(|&n| region.contains(n))
+                .count();
+            4 - shared
+        })
+        .sum()
+}
+
+pub fn bfs_path<T: GridNum, V: Clone, G: Grid<T, V>>(
+    grid: &G,
+    start: Coord<T>,
+    goal: Coord<T>,
+    passable: impl Fn(&V) -> bool,
+    bounds: (Coord<T>, Coord<T>),
+) -> Option<Vec<Coord<T>>> {
+    let mut visited = HashSet::new();
+    let mut predecessors: HashMap<Coord<T>, Coord<T>> = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    visited.insert(start);
+    queue.push_back(start);
+
+    while let Some(current) = queue.pop_front() {
+        if current == goal {
+            let mut path = vec![current];
+            let mut step = current;
+            while let Some(&previous) = predecessors.get(&step) {
+                path.push(previous);
+                step = previous;
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        for neighbor in orthogonal_neighbors(&current, &bounds) {
+            if !grid.get(&neighbor).is_some_and(&passable) || !visited.insert(neighbor) {
+                continue;
+            }
+            predecessors.insert(neighbor, current);
+            queue.push_back(neighbor);
+        }
+    }
+
+    None
+}
+
+pub fn distance_field<T: GridNum, V: Clone, G: Grid<T, V>>(
+    grid: &G,
+    sources: &[Coord<T>],
+    passable: impl Fn(&V) -> bool,
+    bounds: (Coord<T>, Coord<T>),
+) -> HashGrid<T, u64> {
+    // an unbounded map keeps every insert infallible; bounds are enforced by the BFS
+    let mut distances: HashMap<Coord<T>, u64> = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for source in sources {
+        if source.range_contains(&bounds.0, &bounds.1) && !distances.contains_key(source) {
+            distances.insert(*source, 0);
+            queue.push_back(*source);
+        }
+    }
+
+    while let Some(current) = queue.pop_front() {
+        let next_distance = distances[&current] + 1;
+        for neighbor in orthogonal_neighbors(&current, &bounds) {
+            if distances.contains_key(&neighbor) || !grid.get(&neighbor).is_some_and(&passable) {
+                continue;
+            }
+            distances.insert(neighbor, next_distance);
+            queue.push_back(neighbor);
+        }
+    }
+
+    distances.into_iter().collect()
+}
+
+pub fn dijkstra<T: GridNum, V: Clone, G: Grid<T, V>>(
+    grid: &G,
+    start: Coord<T>,
+    goal: Coord<T>,
+    cost: impl Fn(&V) -> Option<u64>,
+    bounds: (Coord<T>, Coord<T>),
+) -> Option<u64> {
+    let mut distances: HashMap<Coord<T>, u64> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    distances.insert(start, 0);
+    heap.push(Reverse((0, start)));
+
+    while let Some(Reverse((total, current))) = heap.pop() {
+        if current == goal {
+            return Some(total);
+        }
+        if distances.get(&current).is_some_and(|&best| total > best) {
+            continue;
+        }
+
+        for neighbor in orthogonal_neighbors(&current, &bounds) {
+            let Some(step_cost) = grid.get(&neighbor).and_then(&cost) else {
+                continue;
+            };
+            let next = total + step_cost;
+            if distances.get(&neighbor).is_none_or(|&best| next < best) {
+                distances.insert(neighbor, next);
+                heap.push(Reverse((next, neighbor)));
+            }
+        }
+    }
+
+    None
+}
+
+/// A* search returning the total cost and path from `start` to `goal`.
+///
+/// The heuristic must be admissible (never overestimate the remaining cost)
+/// for the returned path to be optimal.
+pub fn astar<T: GridNum, V: Clone, G: Grid<T, V>>(
+    grid: &G,
+    start: Coord<T>,
+    goal: Coord<T>,
+    cost: impl Fn(&V) -> Option<u64>,
+    heuristic: impl Fn(&Coord<T>, &Coord<T>) -> u64,
+    bounds: (Coord<T>, Coord<T>),
+) -> Option<(u64, Vec<Coord<T>>)> {
+    let mut distances: HashMap<Coord<T>, u64> = HashMap::new();
+    let mut predecessors: HashMap<Coord<T>, Coord<T>> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    distances.insert(start, 0);
+    heap.push(Reverse((heuristic(&start, &goal), 0, start)));
+
+    while let Some(Reverse((_, total, current))) = heap.pop() {
+        if current == goal {
+            let mut path = vec![current];
+            let mut step = current;
+            while let Some(&previous) = predecessors.get(&step) {
+                path.push(previous);
+                step = previous;
+            }
+            path.reverse();
+            return Some((total, path));
+        }
+        if distances.get(&current).is_some_and(|&best| total > best) {
+            continue;
+        }
+
+        for neighbor in orthogonal_neighbors(&current, &bounds) {
+            let Some(step_cost) = grid.get(&neighbor).and_then(&cost) else {
+                continue;
+            };
+            let next = total + step_cost;
+            if distances.get(&neighbor).is_none_or(|&best| next < best) {
+                distances.insert(neighbor, next);
+                predecessors.insert(neighbor, current);
+                heap.push(Reverse((
+                    next + heuristic(&neighbor, &goal),
+                    next,
+                    neighbor,
+                )));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LinearGrid;
+
+    fn coord(x: i32, y: i32) -> Coord<i32> {
+        Coord::new(x, y)
+    }
+
+    #[test]
+    fn test_bfs_path() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("...\n##.\n...\n").unwrap();
+        let bounds = (coord(0, 0), coord(2, 2));
+        let path = bfs_path(&grid, coord(0, 0), coord(0, 2), |c| *c == '.', bounds).unwrap();
+        assert_eq!(
+            path,
+            vec![
+                coord(0, 0),
+                coord(1, 0),
+                coord(2, 0),
+                coord(2, 1),
+                coord(2, 2),
+                coord(1, 2),
+                coord(0, 2)
+            ]
+        );
+
+        let same = bfs_path(&grid, coord(1, 0), coord(1, 0), |c| *c == '.', bounds);
+        assert_eq!(same, Some(vec![coord(1, 0)]));
+    }
+
+    #[test]
+    fn test_bfs_path_unreachable() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("..\n##\n..\n").unwrap();
+        let bounds = (coord(0, 0), coord(1, 2));
+        assert_eq!(
+            bfs_path(&grid, coord(0, 0), coord(0, 2), |c| *c == '.', bounds),
+            None
+        );
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("191\n111\n#9#\n").unwrap();
+        let bounds = (coord(0, 0), coord(2, 2));
+        let cost = |c: &char| c.to_digit(10).map(u64::from);
+
+        // straight across the 9 costs 10, going around costs 4
+        assert_eq!(
+            dijkstra(&grid, coord(0, 0), coord(2, 0), cost, bounds),
+            Some(4)
+        );
+        assert_eq!(
+            dijkstra(&grid, coord(0, 0), coord(1, 2), cost, bounds),
+            Some(11)
+        );
+        assert_eq!(
+            dijkstra(&grid, coord(0, 0), coord(0, 2), cost, bounds),
+            None
+        );
+        assert_eq!(
+            dijkstra(&grid, coord(1, 1), coord(1, 1), cost, bounds),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn test_astar_matches_dijkstra() {
+        let grid =
+            LinearGrid::<i32, char>::from_str_chars("11911\n19191\n11111\n99919\n11111\n").unwrap();
+        let bounds = (coord(0, 0), coord(4, 4));
+        let cost = |c: &char| c.to_digit(10).map(u64::from);
+        let manhattan = |a: &Coord<i32>, b: &Coord<i32>| a.manhattan_distance(b) as u64;
+
+        let (total, path) =
+            astar(&grid, coord(0, 0), coord(4, 4), cost, manhattan, bounds).unwrap();
+        assert_eq!(
+            Some(total),
+            dijkstra(&grid, coord(0, 0), coord(4, 4), cost, bounds)
+        );
+        assert_eq!(path.first(), Some(&coord(0, 0)));
+        assert_eq!(path.last(), Some(&coord(4, 4)));
+        let path_cost: u64 = path[1..]
+            .iter()
+            .map(|c| cost(grid.get(c).unwrap()).unwrap())
+            .sum();
+        assert_eq!(path_cost, total);
+    }
+
+    #[test]
+    fn test_astar_unreachable() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("1#1\n1#1\n").unwrap();
+        let bounds = (coord(0, 0), coord(2, 1));
+        let cost = |c: &char| c.to_digit(10).map(u64::from);
+        assert_eq!(
+            astar(&grid, coord(0, 0), coord(2, 0), cost, |_, _| 0, bounds),
+            None
+        );
+    }
+
+    #[test]
+    fn test_flood_fill() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("AAB\nBBB\nAAB\n").unwrap();
+        let bounds = (coord(0, 0), coord(2, 2));
+
+        let mut top = flood_fill(&grid, coord(0, 0), bounds, Connectivity::Four);
+        top.sort();
+        assert_eq!(top, vec![coord(0, 0), coord(1, 0)]);
+
+        let mut bottom = flood_fill(&grid, coord(1, 2), bounds, Connectivity::Four);
+        bottom.sort();
+        assert_eq!(bottom, vec![coord(0, 2), coord(1, 2)]);
+
+        assert_eq!(
+            flood_fill(&grid, coord(2, 0), bounds, Connectivity::Four).len(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_flood_fill_count() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("AAB\nBBB\nAAB\n").unwrap();
+        let bounds = (coord(0, 0), coord(2, 2));
+
+        for start in [coord(0, 0), coord(2, 0), coord(0, 2)] {
+            assert_eq!(
+                flood_fill_count(&grid, start, bounds, Connectivity::Four),
+                flood_fill(&grid, start, bounds, Connectivity::Four).len()
+            );
+        }
+        assert_eq!(
+            flood_fill_count(&grid, coord(2, 0), bounds, Connectivity::Four),
+            5
+        );
+        assert_eq!(
+            flood_fill_count(&grid, coord(3, 0), bounds, Connectivity::Four),
+            0
+        );
+    }
+
+    #[test]
+    fn test_flood_fill_connectivity() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("#..\n.#.\n..#\n").unwrap();
+        let bounds = (coord(0, 0), coord(2, 2));
+
+        let four = flood_fill(&grid, coord(0, 0), bounds, Connectivity::Four);
+        assert_eq!(four, vec![coord(0, 0)]);
+
+        let mut eight = flood_fill(&grid, coord(0, 0), bounds, Connectivity::Eight);
+        eight.sort();
+        assert_eq!(eight, vec![coord(0, 0), coord(1, 1), coord(2, 2)]);
+    }
+
+    #[test]
+    fn test_flood_fill_respects_bounds() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("....\n....\n").unwrap();
+        let region = flood_fill(
+            &grid,
+            coord(0, 0),
+            (coord(0, 0), coord(1, 1)),
+            Connectivity::Four,
+        );
+        assert_eq!(region.len(), 4);
+        assert!(region.iter().all(|c| c.x() <= 1));
+    }
+
+    #[test]
+    fn test_connected_components() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("AAB\nCAB\nCCB\n").unwrap();
+        let bounds = (coord(0, 0), coord(2, 2));
+
+        let regions = connected_components(&grid, bounds, Connectivity::Four, |a, b| a == b);
+        let mut sizes: Vec<usize> = regions.iter().map(Vec::len).collect();
+        sizes.sort();
+        assert_eq!(sizes, vec![3, 3, 3]);
+        assert_eq!(regions.iter().map(Vec::len).sum::<usize>(), 9);
+
+        let a_region = regions.iter().find(|r| r.contains(&coord(0, 0))).unwrap();
+        assert!(a_region.contains(&coord(1, 1)));
+        assert!(!a_region.contains(&coord(0, 1)));
+    }
+
+    #[test]
+    fn test_region_perimeter() {
+        let block: HashSet<_> = [coord(0, 0), coord(1, 0), coord(0, 1), coord(1, 1)].into();
+        assert_eq!(region_perimeter(&block), 8);
+
+        let l_shape: HashSet<_, std::hash::RandomState> =
+            [coord(0, 0), coord(0, 1), coord(0, 2), coord(1, 2)]
+                .into_iter()
+                .collect();
+        assert_eq!(region_perimeter(&l_shape), 10);
+
+        let single: HashSet<_> = [Coord::new(0u32, 0u32)].into();
+        assert_eq!(region_perimeter(&single), 4);
+        assert_eq!(region_perimeter(&HashSet::<Coord<i32>>::new()), 0);
+    }
+
+    #[test]
+    fn test_distance_field() {
+        let grid = LinearGrid::<i32, char>::from_str_chars("......\n.##...\n......\n").unwrap();
+        let bounds = (coord(0, 0), coord(5, 2));
+        let field = distance_field(&grid, &[coord(0, 0), coord(5, 2)], |c| *c == '.', bounds);
+
+        assert_eq!(field.get(&coord(0, 0)), Some(&0));
+        assert_eq!(field.get(&coord(5, 2)), Some(&0));
+        assert_eq!(field.get(&coord(1, 0)), Some(&1));
+        assert_eq!(field.get(&coord(5, 0)), Some(&2));
+        assert_eq!(field.get(&coord(2, 2)), Some(&3));
+        assert_eq!(field.get(&coord(3, 0)), Some(&3));
+        assert_eq!(field.get(&coord(1, 1)), None);
+        assert_eq!(field.keys().count(), 16);
+    }
+}
@@ -0,0 +1,351 @@
+use anyhow::{Result, bail};
+use std::fmt::{self, Display};
+
+use crate::{Coord, Grid, GridNum};
+
+#[derive(Debug, Clone, Copy)]
+pub struct StaticGrid<T: GridNum, V: Copy, const W: usize, const H: usize> {
+    _phantom: std::marker::PhantomData<T>,
+    data: [[V; W]; H],
+    empty: V,
+}
+
+impl<T: GridNum, V: Copy, const W: usize, const H: usize> StaticGrid<T, V, W, H> {
+    pub fn new(initial: V) -> Self {
+        Self {
+            _phantom: std::marker::PhantomData,
+            data: [[initial; W]; H],
+            empty: initial,
+        }
+    }
+
+    pub fn with_default() -> Self
+    where
+        V: Default,
+    {
+        Self::new(V::default())
+    }
+
+    /// Walks the cells in row-major order without allocating.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord<T>, V)> + '_ {
+        self.data.iter().enumerate().flat_map(|(y, row)| {
+            row.iter().enumerate().filter_map(move |(x, v)| {
+                Some((Coord::new(T::try_from(x).ok()?, T::try_from(y).ok()?), *v))
+            })
+        })
+    }
+
+    fn get_indices_from_coord(&self, coord: &Coord<T>) -> Option<(usize, usize)> {
+        let x: usize = coord.x().try_into().ok()?;
+        let y: usize = coord.y().try_into().ok()?;
+
+        if x >= W || y >= H {
+            return None;
+        }
+
+        Some((x, y))
+    }
+}
+
+impl<T: GridNum, V: Copy + Default, const W: usize, const H: usize> Default
+    for StaticGrid<T, V, W, H>
+{
+    fn default() -> Self {
+        Self::with_default()
+    }
+}
+
+impl<T: GridNum, V: Copy + Into<char>, const W: usize, const H: usize> Display
+    for StaticGrid<T, V, W, H>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.data.iter().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", Into::<char>::into(*cell))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T: GridNum, V: Copy, const W: usize, const H: usize> Grid<T, V> for StaticGrid<T, V, W, H> {
+    fn clear(&mut self) {
+        self.data = [[self.empty; W]; H];
+    }
+
+    fn check_bounds(&self, key: &Coord<T>) -> Result<()> {
+        if self.get_indices_from_coord(key).is_none() {
+            bail!("Coordinate out of bounds");
+        }
+        Ok(())
+    }
+
+    fn len(&self) -> usize {
+        W * H
+    }
+
+    fn dimensions(&self) -> Option<(T, T)> {
+        let width = T::try_from(W).ok()?;
+        let height = T::try_from(H).ok()?;
+        Some((width, height))
+    }
+
+    fn insert(&mut self, key: Coord<T>, value: V) -> Result<()> {
+        let (x, y) = self
+            .get_indices_from_coord(&key)
+            .ok_or_else(|| anyhow::anyhow!("Coordinate out of bounds"))?;
+        self.data[y][x] = value;
+
+        Ok(())
+    }
+
+    fn get(&self, key: &Coord<T>) -> Option<&V> {
+        let (x, y) = self.get_indices_from_coord(key)?;
+        Some(&self.data[y][x])
+    }
+
+    fn get_mut(&mut self, key: &Coord<T>) -> Option<&mut V> {
+        let (x, y) = self.get_indices_from_coord(key)?;
+        Some(&mut self.data[y][x])
+    }
+
+    fn remove(&mut self, key: &Coord<T>) -> Option<V> {
+        let (x, y) = self.get_indices_from_coord(key)?;
+        Some(std::mem::replace(&mut self.data[y][x], self.empty))
+    }
+
+    fn iter(&self) -> Box<dyn Iterator<Item = (Coord<T>, V)> + '_> {
+        Box::new(StaticGrid::iter(self))
+    }
+
+    fn swap(&mut self, a: &Coord<T>, b: &Coord<T>) -> Result<()> {
+        let (Some((ax, ay)), Some((bx, by))) = (
+            self.get_indices_from_coord(a),
+            self.get_indices_from_coord(b),
+        ) else {
+            bail!("Coordinate out of bounds");
+        };
+        let value_a = self.data[ay][ax];
+        self.data[ay][ax] = self.data[by][bx];
+        self.data[by][bx] = value_a;
+        Ok(())
+    }
+
+    fn count_matching(&self, value: V) -> usize
+    where
+        V: PartialEq,
+    {
+        self.data.iter().flatten().filter(|v| **v == value).count()
+    }
+
+    fn find(&self, value: V) -> Option<Coord<T>>
+    where
+        V: PartialEq,
+    {
+        self.find_all(value).into_iter().next()
+    }
+
+    fn find_all(&self, value: V) -> Vec<Coord<T>>
+    where
+        V: PartialEq,
+    {
+        let mut found = Vec::new();
+        for (y, row) in self.data.iter().enumerate() {
+            for (x, v) in row.iter().enumerate() {
+                if *v == value
+                    && let (Ok(x), Ok(y)) = (T::try_from(x), T::try_from(y))
+                {
+                    found.push(Coord::new(x, y));
+                }
+            }
+        }
+        found
+    }
+
+    fn up_n(&self, coord: &Coord<T>, step: T) -> Option<V> {
+        let new_coord = coord.up_n(Some(step), None)?;
+        self.get(&new_coord).copied()
+    }
+
+    fn down_n(&self, coord: &Coord<T>, step: T) -> Option<V> {
+        let new_coord = coord.down_n(Some(step), None)?;
+        self.get(&new_coord).copied()
+    }
+
+    fn left_n(&self, coord: &Coord<T>, step: T) -> Option<V> {
+        let new_coord = coord.left_n(Some(step), None)?;
+        self.get(&new_coord).copied()
+    }
+
+    fn right_n(&self, coord: &Coord<T>, step: T) -> Option<V> {
+        let new_coord = coord.right_n(Some(step), None)?;
+        self.get(&new_coord).copied()
+    }
+
+    fn matches(&self, key: &Coord<T>, value: V) -> Result<bool>
+    where
+        V: PartialEq,
+    {
+        self.check_bounds(key)?;
+        match self.get(key) {
+            Some(grid_value) => Ok(*grid_value == value),
+            None => Ok(false),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coord(x: i32, y: i32) -> Coord<i32> {
+        Coord::new(x, y)
+    }
+
+    #[test]
+    fn test_insert_and_get() {
+        let mut grid = StaticGrid::<i32, i32, 5, 5>::new(0);
+        let c = coord(1, 2);
+        grid.insert(c, 42).unwrap();
+        assert_eq!(grid.get(&c), Some(&42));
+        assert_eq!(grid.get(&coord(2, 1)), Some(&0));
+    }
+
+    #[test]
+    fn test_with_default() {
+        let grid = StaticGrid::<u8, u32, 2, 2>::with_default();
+        assert_eq!(grid.get(&Coord::new(1, 1)), Some(&0));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut grid = StaticGrid::<i32, i32, 10, 10>::new(0);
+        let c = coord(5, 6);
+        grid.insert(c, 7).unwrap();
+        assert_eq!(grid.remove(&c), Some(7));
+        assert_eq!(grid.get(&c), Some(&0));
+        assert_eq!(grid.remove(&coord(10, 0)), None);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut grid = StaticGrid::<i32, i32, 3, 3>::new(0);
+        let c = coord(1, 1);
+        *grid.get_mut(&c).unwrap() += 5;
+        assert_eq!(grid.get(&c), Some(&5));
+        assert_eq!(grid.get_mut(&coord(0, 3)), None);
+    }
+
+    #[test]
+    fn test_count_matching() {
+        let mut grid = StaticGrid::<i32, bool, 3, 3>::new(false);
+        grid.insert(coord(0, 0), true).unwrap();
+        grid.insert(coord(2, 1), true).unwrap();
+        assert_eq!(grid.count_matching(true), 2);
+        assert_eq!(grid.count_matching(false), 7);
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let grid = StaticGrid::<i32, i32, 4, 3>::new(0);
+        assert_eq!(grid.dimensions(), Some((4, 3)));
+    }
+
+    #[test]
+    fn test_find() {
+        let mut grid = StaticGrid::<i32, char, 3, 3>::new('.');
+        grid.insert(coord(2, 0), 'S').unwrap();
+        grid.insert(coord(1, 2), 'E').unwrap();
+        assert_eq!(grid.find('S'), Some(coord(2, 0)));
+        assert_eq!(grid.find_all('E'), vec![coord(1, 2)]);
+        assert_eq!(grid.find('#'), None);
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut grid = StaticGrid::<i32, i32, 2, 2>::new(0);
+        grid.insert(coord(0, 0), 1).unwrap();
+        grid.insert(coord(1, 1), 2).unwrap();
+        grid.swap(&coord(0, 0), &coord(1, 1)).unwrap();
+        assert_eq!(grid.get(&coord(0, 0)), Some(&2));
+        assert_eq!(grid.get(&coord(1, 1)), Some(&1));
+        assert!(grid.swap(&coord(0, 0), &coord(2, 0)).is_err());
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut grid = StaticGrid::<i32, i32, 2, 2>::new(0);
+        grid.insert(coord(1, 0), 5).unwrap();
+        let cells: Vec<_> = grid.iter().collect();
+        assert_eq!(
+            cells,
+            vec![
+                (coord(0, 0), 0),
+                (coord(1, 0), 5),
+                (coord(0, 1), 0),
+                (coord(1, 1), 0)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_iter_through_trait() {
+        let mut grid = StaticGrid::<u8, u8, 2, 2>::new(1);
+        grid.insert(Coord::new(0, 1), 4).unwrap();
+        let total: u32 = Grid::iter(&grid).map(|(_, v)| u32::from(v)).sum();
+        assert_eq!(total, 7);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut grid = StaticGrid::<i32, char, 2, 2>::new('.');
+        grid.insert(coord(1, 0), '#').unwrap();
+        grid.insert(coord(0, 1), '#').unwrap();
+        assert_eq!(grid.to_string(), ".#\n#.");
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut grid = StaticGrid::<i32, i32, 3, 3>::new(7);
+        let c = coord(2, 2);
+        grid.insert(c, 5).unwrap();
+        grid.clear();
+        assert_eq!(grid.get(&c), Some(&7));
+    }
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = StaticGrid::<i32, i32, 3, 2>::new(0);
+        assert!(grid.insert(coord(2, 1), 1).is_ok());
+        assert!(grid.insert(coord(3, 1), 2).is_err());
+        assert!(grid.insert(coord(1, 2), 2).is_err());
+        assert!(grid.insert(coord(-1, 0), 2).is_err());
+        assert_eq!(grid.get(&coord(3, 1)), None);
+        assert!(grid.matches(&coord(0, 2), 0).is_err());
+    }
+
+    #[test]
+    fn test_up_n() {
+        let mut grid = StaticGrid::<u32, i32, 5, 5>::new(0);
+        let c = Coord::new(2, 2);
+        grid.insert(Coord::new(2, 1), 99).unwrap();
+        assert_eq!(grid.up_n(&c, 1), Some(99));
+        assert_eq!(grid.up_n(&c, 2), Some(0));
+        assert_eq!(grid.up_n(&c, 3), None);
+        assert_eq!(grid.down_n(&c, 3), None);
+        assert_eq!(grid.left_n(&c, 2), Some(0));
+        assert_eq!(grid.right_n(&c, 3), None);
+    }
+
+    #[test]
+    fn test_matches() {
+        let mut grid = StaticGrid::<i32, i32, 5, 5>::new(0);
+        let c = coord(0, 0);
+        grid.insert(c, 123).unwrap();
+        assert!(grid.matches(&c, 123).unwrap());
+        assert!(!grid.matches(&c, 456).unwrap());
+    }
+}
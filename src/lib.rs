@@ -1,7 +1,9 @@
 mod coord;
+mod direction;
 mod grid;
 
 pub use coord::*;
+pub use direction::*;
 pub use grid::*;
 
 pub fn add(left: u64, right: u64) -> u64 {